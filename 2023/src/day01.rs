@@ -0,0 +1,35 @@
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn get_digits(line: &str, with_words: bool) -> Vec<u32> {
+    let mut digits = Vec::new();
+
+    for (i, c) in line.char_indices() {
+        if let Some(digit) = c.to_digit(10) {
+            digits.push(digit);
+        } else if with_words {
+            // Words can overlap (e.g. "twone"), so every position is checked on its own
+            if let Some(word_ix) = DIGIT_WORDS.iter().position(|w| line[i..].starts_with(w)) {
+                digits.push(word_ix as u32 + 1);
+            }
+        }
+    }
+
+    digits
+}
+
+fn get_total(input: &str, with_words: bool) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let digits = get_digits(line, with_words);
+            digits.first().unwrap() * 10 + digits.last().unwrap()
+        })
+        .sum()
+}
+
+pub fn run(input: &str) {
+    println!("Part 1: {}", get_total(input, false));
+    println!("Part 2: {}", get_total(input, true));
+}
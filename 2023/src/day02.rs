@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+struct Game {
+    id: u32,
+    hands: Vec<HashMap<String, u32>>,
+}
+
+fn parse_input(input: &str) -> Vec<Game> {
+    input
+        .lines()
+        .map(|line| {
+            let (game_part, hands_part) = line.split_once(':').unwrap();
+
+            let id = game_part
+                .split_once(' ')
+                .unwrap()
+                .1
+                .trim()
+                .parse()
+                .expect("Incorrect game id");
+
+            let hands = hands_part
+                .split(';')
+                .map(|hand| {
+                    hand.split(',')
+                        .map(|balls| {
+                            let (count, colour) = balls.trim().split_once(' ').unwrap();
+                            (
+                                colour.trim().to_string(),
+                                count.trim().parse().expect("Incorrect ball count"),
+                            )
+                        })
+                        .collect()
+                })
+                .collect();
+
+            Game { id, hands }
+        })
+        .collect()
+}
+
+fn get_count(hand: &HashMap<String, u32>, colour: &str) -> u32 {
+    hand.get(colour).copied().unwrap_or(0)
+}
+
+fn is_possible_hand(hand: &HashMap<String, u32>) -> bool {
+    get_count(hand, "red") <= 12 && get_count(hand, "green") <= 13 && get_count(hand, "blue") <= 14
+}
+
+pub fn run(input: &str) {
+    let games = parse_input(input);
+
+    let part_1: u32 = games
+        .iter()
+        .filter(|game| game.hands.iter().all(is_possible_hand))
+        .map(|game| game.id)
+        .sum();
+
+    println!("Part 1: {part_1}");
+
+    let part_2: u32 = games
+        .iter()
+        .map(|game| {
+            ["red", "green", "blue"]
+                .iter()
+                .map(|colour| {
+                    game.hands
+                        .iter()
+                        .map(|hand| get_count(hand, colour))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .product::<u32>()
+        })
+        .sum();
+
+    println!("Part 2: {part_2}");
+}
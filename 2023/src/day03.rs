@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+struct Number {
+    value: u32,
+    row: usize,
+    col_from: usize,
+    col_to: usize,
+}
+
+fn parse_numbers(schematic: &[Vec<char>]) -> Vec<Number> {
+    let mut numbers = Vec::new();
+
+    for (row, line) in schematic.iter().enumerate() {
+        let mut col = 0;
+
+        while col < line.len() {
+            if !line[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+
+            let col_from = col;
+            let mut value = 0;
+
+            while col < line.len() && line[col].is_ascii_digit() {
+                value = value * 10 + line[col].to_digit(10).unwrap();
+                col += 1;
+            }
+
+            numbers.push(Number {
+                value,
+                row,
+                col_from,
+                col_to: col - 1,
+            });
+        }
+    }
+
+    numbers
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+fn adjacent_positions(number: &Number, height: usize, width: usize) -> Vec<(usize, usize)> {
+    let row_from = number.row.saturating_sub(1);
+    let row_to = (number.row + 1).min(height - 1);
+    let col_from = number.col_from.saturating_sub(1);
+    let col_to = (number.col_to + 1).min(width - 1);
+
+    let mut result = Vec::new();
+
+    for row in row_from..=row_to {
+        for col in col_from..=col_to {
+            if row != number.row || col < number.col_from || col > number.col_to {
+                result.push((row, col));
+            }
+        }
+    }
+
+    result
+}
+
+pub fn run(input: &str) {
+    let schematic: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let height = schematic.len();
+    let width = schematic[0].len();
+
+    let numbers = parse_numbers(&schematic);
+
+    let part_1: u32 = numbers
+        .iter()
+        .filter(|number| {
+            adjacent_positions(number, height, width)
+                .iter()
+                .any(|(row, col)| is_symbol(schematic[*row][*col]))
+        })
+        .map(|number| number.value)
+        .sum();
+
+    println!("Part 1: {part_1}");
+
+    let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+
+    // Like the F# script, which only looks at gears with a row above and below them
+    for number in numbers.iter() {
+        for (row, col) in adjacent_positions(number, height, width) {
+            if schematic[row][col] == '*' && row > 0 && row < height - 1 {
+                gears.entry((row, col)).or_default().push(number.value);
+            }
+        }
+    }
+
+    let part_2: u32 = gears
+        .values()
+        .filter(|numbers| numbers.len() >= 2)
+        .map(|numbers| numbers.iter().product::<u32>())
+        .sum();
+
+    println!("Part 2: {part_2}");
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+fn parse_input(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning, mine) = numbers.split_once('|').unwrap();
+
+            let winning: HashSet<u32> = winning
+                .split_whitespace()
+                .map(|x| x.parse().expect("Incorrect number"))
+                .collect();
+
+            mine.split_whitespace()
+                .map(|x| x.parse::<u32>().expect("Incorrect number"))
+                .filter(|x| winning.contains(x))
+                .count()
+        })
+        .collect()
+}
+
+pub fn run(input: &str) {
+    let card_matches = parse_input(input);
+
+    let part_1: u64 = card_matches
+        .iter()
+        .filter(|matches| **matches > 0)
+        .map(|matches| 1 << (matches - 1))
+        .sum();
+
+    println!("Part 1: {part_1}");
+
+    let mut card_counts = vec![1u64; card_matches.len()];
+
+    for (i, matches) in card_matches.iter().enumerate() {
+        for next in i + 1..(i + 1 + matches).min(card_matches.len()) {
+            card_counts[next] += card_counts[i];
+        }
+    }
+
+    println!("Part 2: {}", card_counts.iter().sum::<u64>());
+}
@@ -0,0 +1,123 @@
+struct RangeEntry {
+    destination_from: i64,
+    source_from: i64,
+    length: i64,
+}
+
+struct Almanac {
+    seeds: Vec<i64>,
+    transformations: Vec<Vec<RangeEntry>>,
+}
+
+fn parse_input(input: &str) -> Almanac {
+    let mut seeds = Vec::new();
+    let mut transformations: Vec<Vec<RangeEntry>> = Vec::new();
+
+    for line in input.lines() {
+        if let Some(seeds_raw) = line.strip_prefix("seeds:") {
+            seeds = seeds_raw
+                .split_whitespace()
+                .map(|x| x.parse().expect("Incorrect seed"))
+                .collect();
+        } else if line.ends_with("map:") {
+            transformations.push(Vec::new());
+        } else if !line.trim().is_empty() {
+            let parts: Vec<i64> = line
+                .split_whitespace()
+                .map(|x| x.parse().expect("Incorrect range"))
+                .collect();
+
+            transformations.last_mut().unwrap().push(RangeEntry {
+                destination_from: parts[0],
+                source_from: parts[1],
+                length: parts[2],
+            });
+        }
+    }
+
+    Almanac {
+        seeds,
+        transformations,
+    }
+}
+
+fn get_destination(source: i64, ranges: &[RangeEntry]) -> i64 {
+    ranges
+        .iter()
+        .find(|r| r.source_from <= source && r.source_from + r.length > source)
+        .map(|r| source - r.source_from + r.destination_from)
+        .unwrap_or(source)
+}
+
+// Ranges are represented as (from, to_exclusive)
+fn get_destination_ranges(sources: Vec<(i64, i64)>, ranges: &[RangeEntry]) -> Vec<(i64, i64)> {
+    let mut output = Vec::new();
+    let mut remaining = sources;
+
+    for range in ranges {
+        let range_to = range.source_from + range.length;
+        let mut not_mapped = Vec::new();
+
+        for (from, to) in remaining {
+            let overlap_from = from.max(range.source_from);
+            let overlap_to = to.min(range_to);
+
+            if overlap_from < overlap_to {
+                let offset = range.destination_from - range.source_from;
+                output.push((overlap_from + offset, overlap_to + offset));
+
+                if from < overlap_from {
+                    not_mapped.push((from, overlap_from));
+                }
+
+                if overlap_to < to {
+                    not_mapped.push((overlap_to, to));
+                }
+            } else {
+                not_mapped.push((from, to));
+            }
+        }
+
+        remaining = not_mapped;
+    }
+
+    output.extend(remaining);
+    output
+}
+
+pub fn run(input: &str) {
+    let almanac = parse_input(input);
+
+    let part_1 = almanac
+        .seeds
+        .iter()
+        .map(|seed| {
+            almanac
+                .transformations
+                .iter()
+                .fold(*seed, |cur, ranges| get_destination(cur, ranges))
+        })
+        .min()
+        .unwrap();
+
+    println!("Part 1: {part_1}");
+
+    let seed_ranges: Vec<(i64, i64)> = almanac
+        .seeds
+        .chunks(2)
+        .map(|pair| (pair[0], pair[0] + pair[1]))
+        .collect();
+
+    let part_2 = almanac
+        .transformations
+        .iter()
+        .fold(seed_ranges, |cur, ranges| {
+            get_destination_ranges(cur, ranges)
+        })
+        .iter()
+        .map(|(from, _)| *from)
+        .min()
+        .unwrap();
+
+    println!("Part 2: {part_2}");
+}
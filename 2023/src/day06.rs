@@ -0,0 +1,47 @@
+fn parse_line(line: &str) -> Vec<i64> {
+    line.split_once(':')
+        .unwrap()
+        .1
+        .split_whitespace()
+        .map(|x| x.parse().expect("Incorrect number"))
+        .collect()
+}
+
+fn parse_line_joined(line: &str) -> i64 {
+    line.split_once(':')
+        .unwrap()
+        .1
+        .split_whitespace()
+        .collect::<String>()
+        .parse()
+        .expect("Incorrect number")
+}
+
+fn ways_to_win(time: i64, distance: i64) -> i64 {
+    // Looking for x where x * (time - x) > distance, so the boundaries are roots of
+    // -x^2 + x * time - (distance + 1) = 0
+    let discriminant = ((time * time - 4 * (distance + 1)) as f64).sqrt();
+    let min_x = ((time as f64 - discriminant) / 2.0).ceil() as i64;
+    let max_x = ((time as f64 + discriminant) / 2.0).floor() as i64;
+
+    max_x - min_x + 1
+}
+
+pub fn run(input: &str) {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let times = parse_line(lines[0]);
+    let distances = parse_line(lines[1]);
+
+    let part_1: i64 = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, distance)| ways_to_win(*time, *distance))
+        .product();
+
+    println!("Part 1: {part_1}");
+
+    let part_2 = ways_to_win(parse_line_joined(lines[0]), parse_line_joined(lines[1]));
+
+    println!("Part 2: {part_2}");
+}
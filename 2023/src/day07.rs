@@ -0,0 +1,73 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+
+fn card_value(card: char, allow_joker: bool) -> u32 {
+    match card {
+        'T' => 10,
+        'J' if allow_joker => 1,
+        'J' => 11,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        c => c.to_digit(10).expect("Unknown card"),
+    }
+}
+
+fn get_hand_power(hand: &str, allow_joker: bool) -> u32 {
+    let mut counts: HashMap<char, u32> = HashMap::new();
+
+    for card in hand.chars() {
+        *counts.entry(card).or_default() += 1;
+    }
+
+    let jokers = if allow_joker {
+        counts.remove(&'J').unwrap_or(0)
+    } else {
+        0
+    };
+
+    let mut group_sizes: Vec<u32> = counts.values().copied().sorted().rev().collect();
+
+    // Jokers are always best used to extend the largest group
+    if group_sizes.is_empty() {
+        group_sizes.push(jokers);
+    } else {
+        group_sizes[0] += jokers;
+    }
+
+    match group_sizes.as_slice() {
+        [5] => 6,
+        [4, ..] => 5,
+        [3, 2] => 4,
+        [3, ..] => 3,
+        [2, 2, ..] => 2,
+        [2, ..] => 1,
+        _ => 0,
+    }
+}
+
+fn get_total_winnings(hands: &[(&str, u64)], allow_joker: bool) -> u64 {
+    hands
+        .iter()
+        .map(|(hand, bid)| {
+            let cards: Vec<u32> = hand.chars().map(|c| card_value(c, allow_joker)).collect();
+            (get_hand_power(hand, allow_joker), cards, *bid)
+        })
+        .sorted()
+        .enumerate()
+        .map(|(i, (_, _, bid))| (i as u64 + 1) * bid)
+        .sum()
+}
+
+pub fn run(input: &str) {
+    let hands: Vec<(&str, u64)> = input
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            (hand, bid.trim().parse().expect("Incorrect bid"))
+        })
+        .collect();
+
+    println!("Part 1: {}", get_total_winnings(&hands, false));
+    println!("Part 2: {}", get_total_winnings(&hands, true));
+}
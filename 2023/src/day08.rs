@@ -0,0 +1,67 @@
+use common::math::lcm;
+use common::parse;
+use std::collections::HashMap;
+
+fn parse_input(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
+    let mut lines = input.lines();
+
+    let instructions = lines.next().unwrap().chars().collect();
+
+    let graph = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (from, left, right) =
+                parse!(line, "{} = ({}, {})", String, String, String).unwrap();
+            (from, (left, right))
+        })
+        .collect();
+
+    (instructions, graph)
+}
+
+fn path_length<F>(
+    start: &str,
+    is_end: F,
+    instructions: &[char],
+    graph: &HashMap<String, (String, String)>,
+) -> i64
+where
+    F: Fn(&str) -> bool,
+{
+    let mut cur = start;
+    let mut steps = 0;
+
+    for instruction in instructions.iter().cycle() {
+        if is_end(cur) {
+            break;
+        }
+
+        let (left, right) = graph.get(cur).expect("Unknown node");
+
+        cur = match instruction {
+            'L' => left,
+            'R' => right,
+            _ => panic!("Unexpected instruction"),
+        };
+
+        steps += 1;
+    }
+
+    steps
+}
+
+pub fn run(input: &str) {
+    let (instructions, graph) = parse_input(input);
+
+    let part_1 = path_length("AAA", |x| x == "ZZZ", &instructions, &graph);
+
+    println!("Part 1: {part_1}");
+
+    let part_2 = graph
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|node| path_length(node, |x| x.ends_with('Z'), &instructions, &graph))
+        .fold(1, lcm);
+
+    println!("Part 2: {part_2}");
+}
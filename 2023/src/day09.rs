@@ -0,0 +1,35 @@
+fn predict_next(numbers: &[i64]) -> i64 {
+    if numbers.iter().all(|x| *x == 0) {
+        return 0;
+    }
+
+    let differences: Vec<i64> = numbers.windows(2).map(|w| w[1] - w[0]).collect();
+
+    numbers.last().unwrap() + predict_next(&differences)
+}
+
+pub fn run(input: &str) {
+    let sequences: Vec<Vec<i64>> = input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|x| x.parse().expect("Incorrect number"))
+                .collect()
+        })
+        .collect();
+
+    let part_1: i64 = sequences.iter().map(|s| predict_next(s)).sum();
+
+    println!("Part 1: {part_1}");
+
+    // Predicting the value before the sequence is the same as predicting after the reversed one
+    let part_2: i64 = sequences
+        .iter()
+        .map(|s| {
+            let reversed: Vec<i64> = s.iter().rev().copied().collect();
+            predict_next(&reversed)
+        })
+        .sum();
+
+    println!("Part 2: {part_2}");
+}
@@ -0,0 +1,77 @@
+use common::polygon::interior_points;
+
+type Coord = (i64, i64);
+
+fn pipe_connections(c: char) -> Vec<Coord> {
+    match c {
+        '|' => vec![(-1, 0), (1, 0)],
+        '-' => vec![(0, -1), (0, 1)],
+        'L' => vec![(-1, 0), (0, 1)],
+        'J' => vec![(-1, 0), (0, -1)],
+        '7' => vec![(0, -1), (1, 0)],
+        'F' => vec![(0, 1), (1, 0)],
+        _ => vec![],
+    }
+}
+
+fn get_char(map: &[Vec<char>], (r, c): Coord) -> char {
+    if r < 0 || c < 0 || r as usize >= map.len() || c as usize >= map[r as usize].len() {
+        '.'
+    } else {
+        map[r as usize][c as usize]
+    }
+}
+
+fn connects_to(map: &[Vec<char>], from: Coord, to: Coord) -> bool {
+    pipe_connections(get_char(map, from))
+        .iter()
+        .any(|(dr, dc)| (from.0 + dr, from.1 + dc) == to)
+}
+
+fn find_loop(map: &[Vec<char>]) -> Vec<Coord> {
+    let start = map
+        .iter()
+        .enumerate()
+        .find_map(|(r, row)| {
+            row.iter()
+                .position(|c| *c == 'S')
+                .map(|c| (r as i64, c as i64))
+        })
+        .expect("Start not found");
+
+    let mut prev = start;
+    let mut cur = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        .iter()
+        .map(|(dr, dc)| (start.0 + dr, start.1 + dc))
+        .find(|next| connects_to(map, *next, start))
+        .expect("Start is not connected");
+
+    let mut path = vec![start];
+
+    while cur != start {
+        path.push(cur);
+
+        let next = pipe_connections(get_char(map, cur))
+            .iter()
+            .map(|(dr, dc)| (cur.0 + dr, cur.1 + dc))
+            .find(|next| *next != prev)
+            .unwrap();
+
+        prev = cur;
+        cur = next;
+    }
+
+    path
+}
+
+pub fn run(input: &str) {
+    let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let path = find_loop(&map);
+
+    println!("Part 1: {}", path.len() / 2);
+
+    // Tiles enclosed by the loop are the lattice points strictly inside of it
+    let enclosed = interior_points(&path);
+
+    println!("Part 2: {enclosed}");
+}
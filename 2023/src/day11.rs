@@ -0,0 +1,64 @@
+fn expanded_offsets(is_empty: &[bool], expansion: i64) -> Vec<i64> {
+    let mut offsets = Vec::with_capacity(is_empty.len());
+    let mut offset = 0;
+
+    for empty in is_empty {
+        offsets.push(offset);
+
+        if *empty {
+            offset += expansion - 1;
+        }
+    }
+
+    offsets
+}
+
+fn sum_of_distances(image: &[Vec<bool>], expansion: i64) -> i64 {
+    let empty_rows: Vec<bool> = image.iter().map(|row| row.iter().all(|x| !x)).collect();
+    let empty_columns: Vec<bool> = (0..image[0].len())
+        .map(|c| image.iter().all(|row| !row[c]))
+        .collect();
+
+    let row_offsets = expanded_offsets(&empty_rows, expansion);
+    let column_offsets = expanded_offsets(&empty_columns, expansion);
+
+    let galaxies: Vec<(i64, i64)> = image
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, is_galaxy)| **is_galaxy)
+                .map(move |(c, _)| (r, c))
+        })
+        .map(|(r, c)| (r as i64 + row_offsets[r], c as i64 + column_offsets[c]))
+        .collect();
+
+    let mut total = 0;
+
+    for (i, (r1, c1)) in galaxies.iter().enumerate() {
+        for (r2, c2) in galaxies.iter().skip(i + 1) {
+            total += (r1 - r2).abs() + (c1 - c2).abs();
+        }
+    }
+
+    total
+}
+
+pub fn run(input: &str) {
+    let image: Vec<Vec<bool>> = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' => true,
+                    '.' => false,
+                    _ => panic!("Unexpected character"),
+                })
+                .collect()
+        })
+        .collect();
+
+    println!("Part 1: {}", sum_of_distances(&image, 2));
+    println!("Part 2: {}", sum_of_distances(&image, 1000000));
+}
@@ -0,0 +1,58 @@
+fn count_arrangements(record: &[char], groups: &[usize]) -> i64 {
+    // combinations[i][j] is the count of arrangements of record[i..] with groups[j..]
+    let mut combinations = vec![vec![0i64; groups.len() + 1]; record.len() + 2];
+    combinations[record.len()][groups.len()] = 1;
+    combinations[record.len() + 1][groups.len()] = 1;
+
+    for i in (0..record.len()).rev() {
+        for j in (0..=groups.len()).rev() {
+            let mut count = 0;
+
+            if record[i] != '#' {
+                count += combinations[i + 1][j];
+            }
+
+            if record[i] != '.' && j < groups.len() {
+                let group_end = i + groups[j];
+
+                let fits = group_end <= record.len()
+                    && record[i..group_end].iter().all(|c| *c != '.')
+                    && (group_end == record.len() || record[group_end] != '#');
+
+                if fits {
+                    count += combinations[group_end + 1][j + 1];
+                }
+            }
+
+            combinations[i][j] = count;
+        }
+    }
+
+    combinations[0][0]
+}
+
+fn parse_line(line: &str, repeat: usize) -> (Vec<char>, Vec<usize>) {
+    let (record, groups) = line.split_once(' ').unwrap();
+
+    let record = vec![record; repeat].join("?").chars().collect();
+    let groups: Vec<usize> = groups
+        .split(',')
+        .map(|x| x.parse().expect("Incorrect group size"))
+        .collect();
+
+    (record, groups.repeat(repeat))
+}
+
+pub fn run(input: &str) {
+    for (part, repeat) in [(1, 1), (2, 5)] {
+        let total: i64 = input
+            .lines()
+            .map(|line| {
+                let (record, groups) = parse_line(line, repeat);
+                count_arrangements(&record, &groups)
+            })
+            .sum();
+
+        println!("Part {part}: {total}");
+    }
+}
@@ -0,0 +1,53 @@
+fn transpose(pattern: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..pattern[0].len())
+        .map(|c| pattern.iter().map(|row| row[c]).collect())
+        .collect()
+}
+
+// Finds the row index above which the pattern reflects with exactly `smudges` differences
+fn find_reflection(pattern: &[Vec<bool>], smudges: usize) -> Option<usize> {
+    (1..pattern.len()).find(|mirror| {
+        let differences: usize = (0..*mirror)
+            .rev()
+            .zip(*mirror..pattern.len())
+            .map(|(above, below)| {
+                pattern[above]
+                    .iter()
+                    .zip(pattern[below].iter())
+                    .filter(|(a, b)| a != b)
+                    .count()
+            })
+            .sum();
+
+        differences == smudges
+    })
+}
+
+fn summarize(pattern: &[Vec<bool>], smudges: usize) -> usize {
+    let vertical = find_reflection(&transpose(pattern), smudges).unwrap_or(0);
+    let horizontal = find_reflection(pattern, smudges).unwrap_or(0);
+
+    vertical + horizontal * 100
+}
+
+pub fn run(input: &str) {
+    let mut patterns: Vec<Vec<Vec<bool>>> = vec![Vec::new()];
+
+    for line in input.lines() {
+        if line.is_empty() {
+            patterns.push(Vec::new());
+        } else {
+            patterns
+                .last_mut()
+                .unwrap()
+                .push(line.chars().map(|c| c == '#').collect());
+        }
+    }
+
+    patterns.retain(|p| !p.is_empty());
+
+    for (part, smudges) in [(1, 0), (2, 1)] {
+        let total: usize = patterns.iter().map(|p| summarize(p, smudges)).sum();
+        println!("Part {part}: {total}");
+    }
+}
@@ -0,0 +1,59 @@
+use common::cycle::state_after;
+
+fn tilt_north(platform: &mut [Vec<char>]) {
+    for c in 0..platform[0].len() {
+        let mut move_to = 0;
+
+        for r in 0..platform.len() {
+            match platform[r][c] {
+                'O' => {
+                    platform[r][c] = '.';
+                    platform[move_to][c] = 'O';
+                    move_to += 1;
+                }
+                '#' => move_to = r + 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn rotate_clockwise(platform: &[Vec<char>]) -> Vec<Vec<char>> {
+    (0..platform[0].len())
+        .map(|c| platform.iter().rev().map(|row| row[c]).collect())
+        .collect()
+}
+
+fn perform_cycle(platform: Vec<Vec<char>>) -> Vec<Vec<char>> {
+    let mut platform = platform;
+
+    // Tilting north and rotating clockwise makes the next tilt go west, then south and east
+    for _ in 0..4 {
+        tilt_north(&mut platform);
+        platform = rotate_clockwise(&platform);
+    }
+
+    platform
+}
+
+fn calculate_load(platform: &[Vec<char>]) -> usize {
+    platform
+        .iter()
+        .enumerate()
+        .map(|(r, row)| row.iter().filter(|c| **c == 'O').count() * (platform.len() - r))
+        .sum()
+}
+
+pub fn run(input: &str) {
+    let platform: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    let mut tilted = platform.clone();
+    tilt_north(&mut tilted);
+
+    println!("Part 1: {}", calculate_load(&tilted));
+
+    // The platform gets into a repeating pattern long before the last cycle
+    let cur = state_after(platform, |p| Some(perform_cycle(p.clone())), 1000000000).unwrap();
+
+    println!("Part 2: {}", calculate_load(&cur));
+}
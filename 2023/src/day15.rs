@@ -0,0 +1,52 @@
+fn get_hash(input: &str) -> usize {
+    input
+        .bytes()
+        .fold(0, |agg, c| ((agg + c as usize) * 17) % 256)
+}
+
+pub fn run(input: &str) {
+    let steps: Vec<&str> = input
+        .lines()
+        .next()
+        .unwrap()
+        .split(',')
+        .filter(|x| !x.is_empty())
+        .collect();
+
+    let part_1: usize = steps.iter().map(|step| get_hash(step)).sum();
+
+    println!("Part 1: {part_1}");
+
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+
+    for step in steps {
+        if let Some(label) = step.strip_suffix('-') {
+            boxes[get_hash(label)].retain(|(l, _)| *l != label);
+        } else if let Some((label, focal_length)) = step.split_once('=') {
+            let focal_length = focal_length.parse().expect("Incorrect focal length");
+            let lenses = &mut boxes[get_hash(label)];
+
+            match lenses.iter_mut().find(|(l, _)| *l == label) {
+                Some(lens) => lens.1 = focal_length,
+                None => lenses.push((label, focal_length)),
+            }
+        } else {
+            panic!("Unexpected command: {step}");
+        }
+    }
+
+    let part_2: usize = boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(box_ix, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(lens_ix, (_, focal_length))| {
+                    (box_ix + 1) * (lens_ix + 1) * focal_length
+                })
+        })
+        .sum();
+
+    println!("Part 2: {part_2}");
+}
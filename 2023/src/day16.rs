@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+type Beam = ((i64, i64), Direction);
+
+fn move_beam(((r, c), direction): Beam) -> Beam {
+    let next = match direction {
+        Direction::Left => (r, c - 1),
+        Direction::Right => (r, c + 1),
+        Direction::Up => (r - 1, c),
+        Direction::Down => (r + 1, c),
+    };
+
+    (next, direction)
+}
+
+fn get_energized(map: &[Vec<char>], start: Beam) -> usize {
+    let mut visited: HashSet<Beam> = HashSet::new();
+    let mut to_visit = vec![start];
+
+    while let Some(beam) = to_visit.pop() {
+        let ((r, c), direction) = beam;
+
+        if r < 0 || c < 0 || r as usize >= map.len() || c as usize >= map[0].len() {
+            continue;
+        }
+
+        if !visited.insert(beam) {
+            continue;
+        }
+
+        let next_directions = match (map[r as usize][c as usize], direction) {
+            ('/', Direction::Left) => vec![Direction::Down],
+            ('/', Direction::Right) => vec![Direction::Up],
+            ('/', Direction::Up) => vec![Direction::Right],
+            ('/', Direction::Down) => vec![Direction::Left],
+            ('\\', Direction::Left) => vec![Direction::Up],
+            ('\\', Direction::Right) => vec![Direction::Down],
+            ('\\', Direction::Up) => vec![Direction::Left],
+            ('\\', Direction::Down) => vec![Direction::Right],
+            ('|', Direction::Left | Direction::Right) => vec![Direction::Up, Direction::Down],
+            ('-', Direction::Up | Direction::Down) => vec![Direction::Left, Direction::Right],
+            _ => vec![direction],
+        };
+
+        for next_direction in next_directions {
+            to_visit.push(move_beam(((r, c), next_direction)));
+        }
+    }
+
+    visited
+        .iter()
+        .map(|(coord, _)| *coord)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn run(input: &str) {
+    let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let height = map.len() as i64;
+    let width = map[0].len() as i64;
+
+    println!(
+        "Part 1: {}",
+        get_energized(&map, ((0, 0), Direction::Right))
+    );
+
+    let mut starts = Vec::new();
+
+    for r in 0..height {
+        starts.push(((r, 0), Direction::Right));
+        starts.push(((r, width - 1), Direction::Left));
+    }
+
+    for c in 0..width {
+        starts.push(((0, c), Direction::Down));
+        starts.push(((height - 1, c), Direction::Up));
+    }
+
+    let part_2 = starts
+        .into_iter()
+        .map(|start| get_energized(&map, start))
+        .max()
+        .unwrap();
+
+    println!("Part 2: {part_2}");
+}
@@ -0,0 +1,87 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn turns(&self) -> [Direction; 2] {
+        match self {
+            Direction::Left | Direction::Right => [Direction::Up, Direction::Down],
+            Direction::Up | Direction::Down => [Direction::Left, Direction::Right],
+        }
+    }
+
+    fn offset(&self) -> (i64, i64) {
+        match self {
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+        }
+    }
+}
+
+// The crucible always moves at least `min_straight` and at most `max_straight` blocks
+// before it turns, so every heap entry represents a position right after a turn
+fn find_cheapest_path(map: &[Vec<u32>], min_straight: i64, max_straight: i64) -> u32 {
+    let height = map.len() as i64;
+    let width = map[0].len() as i64;
+    let end = (height - 1, width - 1);
+
+    let mut heap = BinaryHeap::new();
+    let mut visited: HashSet<((i64, i64), Direction)> = HashSet::new();
+
+    heap.push(Reverse((0, (0, 0), Direction::Right)));
+    heap.push(Reverse((0, (0, 0), Direction::Down)));
+
+    while let Some(Reverse((cost, (r, c), direction))) = heap.pop() {
+        if (r, c) == end {
+            return cost;
+        }
+
+        if !visited.insert(((r, c), direction)) {
+            continue;
+        }
+
+        for next_direction in direction.turns() {
+            let (dr, dc) = next_direction.offset();
+            let mut next_cost = cost;
+
+            for steps in 1..=max_straight {
+                let (nr, nc) = (r + dr * steps, c + dc * steps);
+
+                if nr < 0 || nc < 0 || nr >= height || nc >= width {
+                    break;
+                }
+
+                next_cost += map[nr as usize][nc as usize];
+
+                if steps >= min_straight {
+                    heap.push(Reverse((next_cost, (nr, nc), next_direction)));
+                }
+            }
+        }
+    }
+
+    panic!("Finish not found");
+}
+
+pub fn run(input: &str) {
+    let map: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).expect("Unexpected character"))
+                .collect()
+        })
+        .collect();
+
+    println!("Part 1: {}", find_cheapest_path(&map, 1, 3));
+    println!("Part 2: {}", find_cheapest_path(&map, 4, 10));
+}
@@ -0,0 +1,57 @@
+use common::polygon::lattice_points;
+
+fn parse_part_1(line: &str) -> ((i64, i64), i64) {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+
+    let direction = match parts[0] {
+        "R" => (0, 1),
+        "D" => (1, 0),
+        "L" => (0, -1),
+        "U" => (-1, 0),
+        _ => panic!("Unexpected direction"),
+    };
+
+    (direction, parts[1].parse().expect("Incorrect length"))
+}
+
+fn parse_part_2(line: &str) -> ((i64, i64), i64) {
+    let colour = line
+        .split_whitespace()
+        .nth(2)
+        .unwrap()
+        .trim_start_matches("(#")
+        .trim_end_matches(')');
+
+    let direction = match &colour[5..] {
+        "0" => (0, 1),
+        "1" => (1, 0),
+        "2" => (0, -1),
+        "3" => (-1, 0),
+        _ => panic!("Unexpected direction"),
+    };
+
+    let length = i64::from_str_radix(&colour[..5], 16).expect("Incorrect length");
+
+    (direction, length)
+}
+
+fn lagoon_size(instructions: &[((i64, i64), i64)]) -> i64 {
+    let mut corners = Vec::with_capacity(instructions.len());
+    let mut cur = (0, 0);
+
+    for ((dr, dc), length) in instructions {
+        cur = (cur.0 + dr * length, cur.1 + dc * length);
+        corners.push(cur);
+    }
+
+    // Dug cubes are the lattice points inside of the trench polygon or on it
+    lattice_points(&corners)
+}
+
+pub fn run(input: &str) {
+    let part_1: Vec<_> = input.lines().map(parse_part_1).collect();
+    println!("Part 1: {}", lagoon_size(&part_1));
+
+    let part_2: Vec<_> = input.lines().map(parse_part_2).collect();
+    println!("Part 2: {}", lagoon_size(&part_2));
+}
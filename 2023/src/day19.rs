@@ -0,0 +1,145 @@
+use common::parse::ints_n;
+use std::collections::HashMap;
+
+struct Rule {
+    property: usize,
+    is_less: bool,
+    value: u64,
+    target: String,
+}
+
+struct Workflow {
+    rules: Vec<Rule>,
+    otherwise: String,
+}
+
+fn property_index(property: &str) -> usize {
+    match property {
+        "x" => 0,
+        "m" => 1,
+        "a" => 2,
+        "s" => 3,
+        _ => panic!("Unknown property {property}"),
+    }
+}
+
+fn parse_workflow(line: &str) -> (String, Workflow) {
+    let (name, rest) = line.split_once('{').unwrap();
+    let mut entries: Vec<&str> = rest.trim_end_matches('}').split(',').collect();
+    let otherwise = entries.pop().unwrap().to_string();
+
+    let rules = entries
+        .into_iter()
+        .map(|entry| {
+            let (condition, target) = entry.split_once(':').unwrap();
+            let is_less = condition.contains('<');
+            let (property, value) = condition.split_once(['<', '>']).unwrap();
+
+            Rule {
+                property: property_index(property),
+                is_less,
+                value: value.parse().expect("Incorrect value"),
+                target: target.to_string(),
+            }
+        })
+        .collect();
+
+    (name.to_string(), Workflow { rules, otherwise })
+}
+
+fn parse_input(input: &str) -> (HashMap<String, Workflow>, Vec<[u64; 4]>) {
+    let (workflows_raw, items_raw) = input.split_once("\n\n").unwrap();
+
+    let workflows = workflows_raw.lines().map(parse_workflow).collect();
+
+    let items = items_raw
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| ints_n(line).unwrap())
+        .collect();
+
+    (workflows, items)
+}
+
+fn is_accepted(workflows: &HashMap<String, Workflow>, item: &[u64; 4]) -> bool {
+    let mut cur = "in";
+
+    loop {
+        match cur {
+            "A" => return true,
+            "R" => return false,
+            _ => {}
+        }
+
+        let workflow = workflows.get(cur).expect("Unknown workflow");
+
+        cur = workflow
+            .rules
+            .iter()
+            .find(|rule| {
+                let value = item[rule.property];
+
+                if rule.is_less {
+                    value < rule.value
+                } else {
+                    value > rule.value
+                }
+            })
+            .map(|rule| rule.target.as_str())
+            .unwrap_or(workflow.otherwise.as_str());
+    }
+}
+
+// Ranges are inclusive on both ends
+fn count_accepted(
+    workflows: &HashMap<String, Workflow>,
+    state: &str,
+    ranges: [(u64, u64); 4],
+) -> u64 {
+    if ranges.iter().any(|(from, to)| from > to) {
+        return 0;
+    }
+
+    match state {
+        "A" => return ranges.iter().map(|(from, to)| to - from + 1).product(),
+        "R" => return 0,
+        _ => {}
+    }
+
+    let workflow = workflows.get(state).expect("Unknown workflow");
+    let mut remaining = ranges;
+    let mut total = 0;
+
+    for rule in workflow.rules.iter() {
+        let (from, to) = remaining[rule.property];
+        let mut matching = remaining;
+
+        if rule.is_less {
+            matching[rule.property] = (from, to.min(rule.value - 1));
+            remaining[rule.property] = (from.max(rule.value), to);
+        } else {
+            matching[rule.property] = (from.max(rule.value + 1), to);
+            remaining[rule.property] = (from, to.min(rule.value));
+        }
+
+        total += count_accepted(workflows, &rule.target, matching);
+    }
+
+    total + count_accepted(workflows, &workflow.otherwise, remaining)
+}
+
+pub fn run(input: &str) {
+    let (workflows, items) = parse_input(input);
+
+    let part_1: u64 = items
+        .iter()
+        .filter(|item| is_accepted(&workflows, item))
+        .map(|item| item.iter().sum::<u64>())
+        .sum();
+
+    println!("Part 1: {part_1}");
+
+    let part_2 = count_accepted(&workflows, "in", [(1, 4000); 4]);
+
+    println!("Part 2: {part_2}");
+}
@@ -0,0 +1,144 @@
+use common::math::lcm;
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+struct Module {
+    module_type: ModuleType,
+    outputs: Vec<String>,
+}
+
+struct Machine {
+    modules: HashMap<String, Module>,
+    flip_flops_on: HashMap<String, bool>,
+    last_inputs: HashMap<String, HashMap<String, bool>>,
+}
+
+fn parse_input(input: &str) -> Machine {
+    let mut modules = HashMap::new();
+    let mut last_inputs: HashMap<String, HashMap<String, bool>> = HashMap::new();
+
+    for line in input.lines() {
+        let (left, right) = line.split_once(" -> ").unwrap();
+
+        let (name, module_type) = match left.chars().next() {
+            Some('%') => (&left[1..], ModuleType::FlipFlop),
+            Some('&') => (&left[1..], ModuleType::Conjunction),
+            Some('b') => (left, ModuleType::Broadcaster),
+            _ => panic!("Invalid module input {left}"),
+        };
+
+        let outputs: Vec<String> = right.split(',').map(|x| x.trim().to_string()).collect();
+
+        for output in outputs.iter() {
+            last_inputs
+                .entry(output.clone())
+                .or_default()
+                .insert(name.to_string(), false);
+        }
+
+        modules.insert(
+            name.to_string(),
+            Module {
+                module_type,
+                outputs,
+            },
+        );
+    }
+
+    Machine {
+        modules,
+        flip_flops_on: HashMap::new(),
+        last_inputs,
+    }
+}
+
+// Presses the button once and reports every sent pulse as (from, to, is_high)
+fn press_button<F>(machine: &mut Machine, mut on_pulse: F)
+where
+    F: FnMut(&str, &str, bool),
+{
+    let mut queue: VecDeque<(String, String, bool)> = VecDeque::new();
+    queue.push_back(("button".to_string(), "broadcaster".to_string(), false));
+
+    while let Some((from, to, is_high)) = queue.pop_front() {
+        on_pulse(&from, &to, is_high);
+
+        let Some(module) = machine.modules.get(&to) else {
+            continue;
+        };
+
+        let output = match module.module_type {
+            ModuleType::Broadcaster => Some(is_high),
+            ModuleType::FlipFlop if is_high => None,
+            ModuleType::FlipFlop => {
+                let state = machine.flip_flops_on.entry(to.clone()).or_insert(false);
+                *state = !*state;
+                Some(*state)
+            }
+            ModuleType::Conjunction => {
+                let inputs = machine.last_inputs.get_mut(&to).unwrap();
+                inputs.insert(from, is_high);
+                Some(!inputs.values().all(|x| *x))
+            }
+        };
+
+        if let Some(output_high) = output {
+            for next in module.outputs.iter() {
+                queue.push_back((to.clone(), next.clone(), output_high));
+            }
+        }
+    }
+}
+
+pub fn run(input: &str) {
+    let mut machine = parse_input(input);
+    let mut low_count: i64 = 0;
+    let mut high_count: i64 = 0;
+
+    for _ in 0..1000 {
+        press_button(&mut machine, |_, _, is_high| {
+            if is_high {
+                high_count += 1;
+            } else {
+                low_count += 1;
+            }
+        });
+    }
+
+    println!("Part 1: {}", low_count * high_count);
+
+    // This is tailored for the input: "rx" is fed by a single conjunction whose inputs
+    // each send a high pulse periodically. The low pulse to "rx" happens once all the
+    // periods align.
+    let mut machine = parse_input(input);
+
+    let Some(rx_inputs) = machine.last_inputs.get("rx") else {
+        println!("Part 2: no rx module");
+        return;
+    };
+
+    let feeder = rx_inputs.keys().next().unwrap().clone();
+    let mut periods: HashMap<String, i64> = HashMap::new();
+    let feeder_inputs = machine.last_inputs.get(&feeder).unwrap().len();
+    let mut presses = 0;
+
+    while periods.len() < feeder_inputs {
+        presses += 1;
+
+        press_button(&mut machine, |from, to, is_high| {
+            if is_high && to == feeder {
+                periods.entry(from.to_string()).or_insert(presses);
+            }
+        });
+    }
+
+    let part_2 = periods.values().fold(1, |agg, x| lcm(agg, *x));
+
+    println!("Part 2: {part_2}");
+}
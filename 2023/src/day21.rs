@@ -0,0 +1,68 @@
+use std::collections::{HashSet, VecDeque};
+
+type Coord = (i64, i64);
+
+fn count_reachable(map: &[Vec<char>], start: Coord, steps: i64) -> i64 {
+    let height = map.len() as i64;
+    let width = map[0].len() as i64;
+
+    let mut seen: HashSet<Coord> = HashSet::from([start]);
+    let mut queue: VecDeque<(Coord, i64)> = VecDeque::from([(start, 0)]);
+    let mut count = 0;
+
+    while let Some(((r, c), distance)) = queue.pop_front() {
+        // Every plot reached with the same parity can be returned to by stepping back and forth
+        if distance % 2 == steps % 2 {
+            count += 1;
+        }
+
+        if distance == steps {
+            continue;
+        }
+
+        for next in [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)] {
+            let tile = map[next.0.rem_euclid(height) as usize][next.1.rem_euclid(width) as usize];
+
+            if tile != '#' && seen.insert(next) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    count
+}
+
+pub fn run(input: &str) {
+    let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    let start = map
+        .iter()
+        .enumerate()
+        .find_map(|(r, row)| {
+            row.iter()
+                .position(|c| *c == 'S')
+                .map(|c| (r as i64, c as i64))
+        })
+        .expect("Start not found");
+
+    println!("Part 1: {}", count_reachable(&map, start, 64));
+
+    // The grid is a square with the start in the middle and clear paths to the sides,
+    // so the count grows quadratically with every grid width walked.
+    // f(n) is the count after half + n * side steps.
+    let step_count: i64 = 26501365;
+    let side = map.len() as i64;
+    let half = start.0;
+
+    let f0 = count_reachable(&map, start, half);
+    let f1 = count_reachable(&map, start, half + side);
+    let f2 = count_reachable(&map, start, half + 2 * side);
+
+    let c = f0;
+    let a = (f2 - 2 * f1 + f0) / 2;
+    let b = f1 - f0 - a;
+
+    let n = (step_count - half) / side;
+
+    println!("Part 2: {}", a * n * n + b * n + c);
+}
@@ -0,0 +1,108 @@
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, Clone, Copy)]
+struct Brick {
+    from: [i64; 3],
+    to: [i64; 3],
+}
+
+fn parse_input(input: &str) -> Vec<Brick> {
+    input
+        .lines()
+        .map(|line| {
+            let (from, to) = line.split_once('~').unwrap();
+            let parse_point = |point: &str| {
+                let coords: Vec<i64> = point
+                    .split(',')
+                    .map(|x| x.parse().expect("Incorrect coordinate"))
+                    .collect();
+                [coords[0], coords[1], coords[2]]
+            };
+
+            let from = parse_point(from);
+            let to = parse_point(to);
+
+            Brick {
+                from: [from[0].min(to[0]), from[1].min(to[1]), from[2].min(to[2])],
+                to: [from[0].max(to[0]), from[1].max(to[1]), from[2].max(to[2])],
+            }
+        })
+        .collect()
+}
+
+fn overlaps_horizontally(a: &Brick, b: &Brick) -> bool {
+    a.from[0] <= b.to[0] && a.to[0] >= b.from[0] && a.from[1] <= b.to[1] && a.to[1] >= b.from[1]
+}
+
+// Settles the bricks and returns, for every settled brick, the indices of bricks supporting it
+fn settle(bricks: &mut [Brick]) -> Vec<Vec<usize>> {
+    bricks.sort_by_key(|b| b.from[2]);
+
+    let mut supported_by: Vec<Vec<usize>> = Vec::with_capacity(bricks.len());
+
+    for i in 0..bricks.len() {
+        let brick = bricks[i];
+
+        let highest = (0..i)
+            .filter(|j| overlaps_horizontally(&brick, &bricks[*j]))
+            .map(|j| bricks[j].to[2])
+            .max()
+            .unwrap_or(0);
+
+        let supports = (0..i)
+            .filter(|j| highest > 0 && bricks[*j].to[2] == highest)
+            .filter(|j| overlaps_horizontally(&brick, &bricks[*j]))
+            .collect();
+
+        let fall = brick.from[2] - highest - 1;
+        bricks[i].from[2] -= fall;
+        bricks[i].to[2] -= fall;
+
+        supported_by.push(supports);
+    }
+
+    supported_by
+}
+
+pub fn run(input: &str) {
+    let mut bricks = parse_input(input);
+    let supported_by = settle(&mut bricks);
+
+    let mut supporting: Vec<Vec<usize>> = vec![Vec::new(); bricks.len()];
+
+    for (i, supports) in supported_by.iter().enumerate() {
+        for s in supports {
+            supporting[*s].push(i);
+        }
+    }
+
+    let cannot_be_removed: HashSet<usize> = supported_by
+        .iter()
+        .filter(|supports| supports.len() == 1)
+        .map(|supports| supports[0])
+        .collect();
+
+    println!("Part 1: {}", bricks.len() - cannot_be_removed.len());
+
+    let mut part_2 = 0;
+
+    for removed in 0..bricks.len() {
+        let mut falling: HashSet<usize> = HashSet::from([removed]);
+        let mut queue = VecDeque::from([removed]);
+
+        while let Some(cur) = queue.pop_front() {
+            for next in supporting[cur].iter() {
+                if !falling.contains(next)
+                    && supported_by[*next].iter().all(|s| falling.contains(s))
+                {
+                    falling.insert(*next);
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        part_2 += falling.len() - 1;
+    }
+
+    println!("Part 2: {part_2}");
+}
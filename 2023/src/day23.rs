@@ -0,0 +1,131 @@
+use common::bits::BitSet;
+use std::collections::{HashMap, HashSet};
+
+type Coord = (usize, usize);
+
+fn neighbours(map: &[Vec<char>], (r, c): Coord, respect_slopes: bool) -> Vec<Coord> {
+    let candidates = match (respect_slopes, map[r][c]) {
+        (true, '>') => vec![(r as i64, c as i64 + 1)],
+        (true, '<') => vec![(r as i64, c as i64 - 1)],
+        (true, '^') => vec![(r as i64 - 1, c as i64)],
+        (true, 'v') => vec![(r as i64 + 1, c as i64)],
+        _ => vec![
+            (r as i64, c as i64 - 1),
+            (r as i64 - 1, c as i64),
+            (r as i64 + 1, c as i64),
+            (r as i64, c as i64 + 1),
+        ],
+    };
+
+    candidates
+        .into_iter()
+        .filter(|(rr, cc)| *rr >= 0 && *cc >= 0)
+        .map(|(rr, cc)| (rr as usize, cc as usize))
+        .filter(|(rr, cc)| *rr < map.len() && *cc < map[0].len() && map[*rr][*cc] != '#')
+        .collect()
+}
+
+// Reduces the maze to the graph of junctions (plus start and end) with the lengths
+// of corridors between them
+fn build_graph(
+    map: &[Vec<char>],
+    start: Coord,
+    end: Coord,
+    respect_slopes: bool,
+) -> Vec<Vec<(usize, usize)>> {
+    let mut junctions: Vec<Coord> = vec![start, end];
+
+    for (r, row) in map.iter().enumerate() {
+        for (c, tile) in row.iter().enumerate() {
+            if *tile != '#' && neighbours(map, (r, c), false).len() > 2 {
+                junctions.push((r, c));
+            }
+        }
+    }
+
+    let junction_ix: HashMap<Coord, usize> =
+        junctions.iter().enumerate().map(|(i, j)| (*j, i)).collect();
+
+    junctions
+        .iter()
+        .map(|junction| {
+            let mut edges = Vec::new();
+
+            for first in neighbours(map, *junction, respect_slopes) {
+                let mut visited: HashSet<Coord> = HashSet::from([*junction]);
+                let mut cur = first;
+                let mut length = 1;
+
+                loop {
+                    if let Some(ix) = junction_ix.get(&cur) {
+                        edges.push((*ix, length));
+                        break;
+                    }
+
+                    visited.insert(cur);
+
+                    match neighbours(map, cur, respect_slopes)
+                        .into_iter()
+                        .find(|n| !visited.contains(n))
+                    {
+                        Some(next) => {
+                            cur = next;
+                            length += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+
+            edges
+        })
+        .collect()
+}
+
+fn longest_path(
+    graph: &[Vec<(usize, usize)>],
+    cur: usize,
+    target: usize,
+    visited: &mut BitSet,
+) -> Option<usize> {
+    if cur == target {
+        return Some(0);
+    }
+
+    let mut longest = None;
+
+    for (next, length) in &graph[cur] {
+        if visited.contains(*next) {
+            continue;
+        }
+
+        visited.insert(*next);
+
+        if let Some(rest) = longest_path(graph, *next, target, visited) {
+            longest = longest.max(Some(rest + length));
+        }
+
+        visited.remove(*next);
+    }
+
+    longest
+}
+
+pub fn run(input: &str) {
+    let map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    let start = (0, map[0].iter().position(|c| *c == '.').unwrap());
+    let last_row = map.len() - 1;
+    let end = (
+        last_row,
+        map[last_row].iter().position(|c| *c == '.').unwrap(),
+    );
+
+    for (part, respect_slopes) in [(1, true), (2, false)] {
+        let graph = build_graph(&map, start, end, respect_slopes);
+        let mut visited = BitSet::from_indices(graph.len(), [0]);
+        let length = longest_path(&graph, 0, 1, &mut visited).expect("No path found");
+
+        println!("Part {part}: {length}");
+    }
+}
@@ -0,0 +1,228 @@
+#[derive(Debug, Clone, Copy)]
+struct Hailstone {
+    position: [i128; 3],
+    velocity: [i128; 3],
+}
+
+fn parse_input(input: &str) -> Vec<Hailstone> {
+    input
+        .lines()
+        .map(|line| {
+            let (position, velocity) = line.split_once('@').unwrap();
+            let parse_vector = |vector: &str| {
+                let coords: Vec<i128> = vector
+                    .split(',')
+                    .map(|x| x.trim().parse().expect("Incorrect coordinate"))
+                    .collect();
+                [coords[0], coords[1], coords[2]]
+            };
+
+            Hailstone {
+                position: parse_vector(position),
+                velocity: parse_vector(velocity),
+            }
+        })
+        .collect()
+}
+
+fn intersect_in_area(h1: &Hailstone, h2: &Hailstone, area_from: f64, area_to: f64) -> bool {
+    // h1.p + h1.v * a = h2.p + h2.v * b, solved for a and b in the XY plane
+    let det = h1.velocity[0] * h2.velocity[1] - h1.velocity[1] * h2.velocity[0];
+
+    if det == 0 {
+        return false;
+    }
+
+    let dx = h2.position[0] - h1.position[0];
+    let dy = h2.position[1] - h1.position[1];
+
+    let a = (dx * h2.velocity[1] - dy * h2.velocity[0]) as f64 / det as f64;
+    let b = (dx * h1.velocity[1] - dy * h1.velocity[0]) as f64 / det as f64;
+
+    let x = h1.position[0] as f64 + h1.velocity[0] as f64 * a;
+    let y = h1.position[1] as f64 + h1.velocity[1] as f64 * a;
+
+    a >= 0.0 && b >= 0.0 && x >= area_from && x <= area_to && y >= area_from && y <= area_to
+}
+
+// Velocity of a hailstone in the XY plane in the frame of the rock
+fn relative_velocity(h: &Hailstone, rock: [i128; 2]) -> [i128; 2] {
+    [h.velocity[0] - rock[0], h.velocity[1] - rock[1]]
+}
+
+fn is_parallel(v1: [i128; 2], v2: [i128; 2]) -> bool {
+    v1[0] * v2[1] - v1[1] * v2[0] == 0
+}
+
+// Finds where the lines of h1 and h2 meet in the XY plane when the rock velocity is subtracted
+// from both of them, which must not make them parallel. Returns `None` if they meet off the
+// integer grid.
+fn meeting_point(h1: &Hailstone, h2: &Hailstone, rock: [i128; 2]) -> Option<[i128; 2]> {
+    let v1 = relative_velocity(h1, rock);
+    let v2 = relative_velocity(h2, rock);
+
+    let det = v1[0] * v2[1] - v1[1] * v2[0];
+    let dx = h2.position[0] - h1.position[0];
+    let dy = h2.position[1] - h1.position[1];
+    let t1_numerator = dx * v2[1] - dy * v2[0];
+
+    if t1_numerator % det != 0 {
+        return None;
+    }
+
+    let t1 = t1_numerator / det;
+
+    Some([h1.position[0] + v1[0] * t1, h1.position[1] + v1[1] * t1])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hit {
+    Never,
+    At(i128),
+    // The hailstone stands still at the point in the frame of the rock, so only Z can tell when
+    Unknown,
+}
+
+// When the hailstone passes through the point in the XY plane in the frame of the rock
+fn hit_time(h: &Hailstone, rock: [i128; 2], point: [i128; 2]) -> Hit {
+    let v = relative_velocity(h, rock);
+    let d = [point[0] - h.position[0], point[1] - h.position[1]];
+
+    if !is_parallel(d, v) {
+        return Hit::Never;
+    }
+
+    let (distance, speed) = if v[0] != 0 {
+        (d[0], v[0])
+    } else if v[1] != 0 {
+        (d[1], v[1])
+    } else if d == [0, 0] {
+        return Hit::Unknown;
+    } else {
+        return Hit::Never;
+    };
+
+    if distance % speed != 0 || distance / speed < 0 {
+        Hit::Never
+    } else {
+        Hit::At(distance / speed)
+    }
+}
+
+// Rock starting position for a rock velocity in the XY plane, if there is one hitting every
+// hailstone at a whole non-negative time
+fn rock_with_velocity(hailstones: &[Hailstone], rock: [i128; 2]) -> Option<[i128; 3]> {
+    // Any hailstone whose line is not parallel to the first one pins down the point. If all of
+    // them are parallel, the point is not pinned down and the velocity is skipped.
+    let first = relative_velocity(&hailstones[0], rock);
+    let other = hailstones
+        .iter()
+        .skip(1)
+        .find(|h| !is_parallel(first, relative_velocity(h, rock)))?;
+    let point = meeting_point(&hailstones[0], other, rock)?;
+
+    let hits = hailstones
+        .iter()
+        .map(|h| match hit_time(h, rock, point) {
+            Hit::Never => None,
+            hit => Some(hit),
+        })
+        .collect::<Option<Vec<Hit>>>()?;
+
+    // Two hailstones hit at different times give the Z velocity
+    let timed: Vec<(&Hailstone, i128)> = hailstones
+        .iter()
+        .zip(&hits)
+        .filter_map(|(h, hit)| match hit {
+            Hit::At(t) => Some((h, *t)),
+            _ => None,
+        })
+        .collect();
+
+    let (h1, t1) = timed[0];
+    let (h2, t2) = *timed.iter().find(|(_, t)| *t != t1)?;
+
+    let z1 = h1.position[2] + h1.velocity[2] * t1;
+    let z2 = h2.position[2] + h2.velocity[2] * t2;
+
+    if (z2 - z1) % (t2 - t1) != 0 {
+        return None;
+    }
+
+    let vz = (z2 - z1) / (t2 - t1);
+    let z = z1 - vz * t1;
+
+    let hits_in_z = hailstones.iter().zip(&hits).all(|(h, hit)| match hit {
+        Hit::At(t) => z + vz * t == h.position[2] + h.velocity[2] * t,
+        // z + vz * t = pz + hvz * t has to have a whole non-negative solution
+        _ => {
+            let distance = h.position[2] - z;
+            let speed = vz - h.velocity[2];
+
+            if speed == 0 {
+                distance == 0
+            } else {
+                distance % speed == 0 && distance / speed >= 0
+            }
+        }
+    });
+
+    hits_in_z.then_some([point[0], point[1], z])
+}
+
+// In the frame of the rock, every hailstone passes through the rock's starting position.
+// The rock velocity is searched in the XY plane in squares growing around 0, up to a few
+// times the fastest hailstone, and the Z coordinate is then derived from two hit times.
+fn find_rock(hailstones: &[Hailstone]) -> Result<[i128; 3], String> {
+    if hailstones.len() < 3 {
+        return Err(String::from(
+            "At least 3 hailstones are needed to find the rock",
+        ));
+    }
+
+    let fastest = hailstones
+        .iter()
+        .flat_map(|h| [h.velocity[0].abs(), h.velocity[1].abs()])
+        .max()
+        .unwrap();
+    let limit = (4 * fastest).max(1000);
+
+    for radius in 0..=limit {
+        for a in -radius..=radius {
+            let candidates = [[a, -radius], [a, radius], [-radius, a], [radius, a]];
+
+            for rock in candidates {
+                if let Some(position) = rock_with_velocity(hailstones, rock) {
+                    return Ok(position);
+                }
+            }
+        }
+    }
+
+    Err(format!(
+        "No rock velocity with X and Y components within {limit} hits every hailstone"
+    ))
+}
+
+pub fn run(input: &str) {
+    let hailstones = parse_input(input);
+
+    let area_from = 200000000000000.0;
+    let area_to = 400000000000000.0;
+
+    let mut part_1 = 0;
+
+    for (i, h1) in hailstones.iter().enumerate() {
+        for h2 in hailstones.iter().skip(i + 1) {
+            if intersect_in_area(h1, h2, area_from, area_to) {
+                part_1 += 1;
+            }
+        }
+    }
+
+    println!("Part 1: {part_1}");
+
+    let rock = find_rock(&hailstones).unwrap_or_else(|error| panic!("{error}"));
+
+    println!("Part 2: {}", rock.iter().sum::<i128>());
+}
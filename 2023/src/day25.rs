@@ -0,0 +1,90 @@
+use std::collections::{HashMap, VecDeque};
+
+fn parse_input(input: &str) -> Vec<Vec<usize>> {
+    let mut node_ix: HashMap<&str, usize> = HashMap::new();
+    let mut graph: Vec<Vec<usize>> = Vec::new();
+
+    let mut get_ix = |name, graph: &mut Vec<Vec<usize>>| {
+        *node_ix.entry(name).or_insert_with(|| {
+            graph.push(Vec::new());
+            graph.len() - 1
+        })
+    };
+
+    for line in input.lines() {
+        let (from, to_nodes) = line.split_once(':').unwrap();
+        let from_ix = get_ix(from.trim(), &mut graph);
+
+        for to in to_nodes.split_whitespace() {
+            let to_ix = get_ix(to, &mut graph);
+            graph[from_ix].push(to_ix);
+            graph[to_ix].push(from_ix);
+        }
+    }
+
+    graph
+}
+
+// Pushes as many edge-disjoint paths from source to sink as possible, stopping once
+// `limit` is exceeded. Returns the found path count and the nodes reachable from
+// source in the remaining residual graph.
+fn max_flow(graph: &[Vec<usize>], source: usize, sink: usize, limit: usize) -> (usize, Vec<bool>) {
+    let mut flow: HashMap<(usize, usize), i32> = HashMap::new();
+    let mut paths = 0;
+
+    loop {
+        let mut previous: Vec<Option<usize>> = vec![None; graph.len()];
+        let mut reached = vec![false; graph.len()];
+        let mut queue = VecDeque::from([source]);
+        reached[source] = true;
+
+        while let Some(cur) = queue.pop_front() {
+            if cur == sink {
+                break;
+            }
+
+            for next in graph[cur].iter() {
+                let used = flow.get(&(cur, *next)).copied().unwrap_or(0);
+
+                if !reached[*next] && used < 1 {
+                    reached[*next] = true;
+                    previous[*next] = Some(cur);
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        if !reached[sink] || paths > limit {
+            return (paths, reached);
+        }
+
+        let mut cur = sink;
+
+        while let Some(prev) = previous[cur] {
+            *flow.entry((prev, cur)).or_default() += 1;
+            *flow.entry((cur, prev)).or_default() -= 1;
+            cur = prev;
+        }
+
+        paths += 1;
+    }
+}
+
+pub fn run(input: &str) {
+    let graph = parse_input(input);
+    let cut_size = 3;
+
+    // The source is on one side of the cut, so some sink has to be on the other side
+    // and exactly `cut_size` edge-disjoint paths lead to it
+    for sink in 1..graph.len() {
+        let (paths, reachable) = max_flow(&graph, 0, sink, cut_size);
+
+        if paths == cut_size {
+            let component = reachable.iter().filter(|x| **x).count();
+            println!("Part 1: {}", component * (graph.len() - component));
+            return;
+        }
+    }
+
+    panic!("No cut of size {cut_size} found");
+}
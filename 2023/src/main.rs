@@ -0,0 +1,243 @@
+use common::input::normalize;
+use git2::Config as gitConfig;
+use reqwest::blocking::Client;
+use reqwest::header::{COOKIE, USER_AGENT};
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::io::{BufReader, Write};
+use std::process::Command;
+use std::time::Instant;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+fn day_func(day: i32) -> fn(&str) {
+    match day {
+        1 => day01::run,
+        2 => day02::run,
+        3 => day03::run,
+        4 => day04::run,
+        5 => day05::run,
+        6 => day06::run,
+        7 => day07::run,
+        8 => day08::run,
+        9 => day09::run,
+        10 => day10::run,
+        11 => day11::run,
+        12 => day12::run,
+        13 => day13::run,
+        14 => day14::run,
+        15 => day15::run,
+        16 => day16::run,
+        17 => day17::run,
+        18 => day18::run,
+        19 => day19::run,
+        20 => day20::run,
+        21 => day21::run,
+        22 => day22::run,
+        23 => day23::run,
+        24 => day24::run,
+        25 => day25::run,
+        _ => unreachable!(),
+    }
+}
+
+fn input_file_path(day: i32) -> String {
+    format!("./input/day{:02}.txt", day)
+}
+
+fn run_day(day: i32) {
+    let path = input_file_path(day);
+    let input = normalize(&fs::read_to_string(&path).unwrap());
+
+    let start_time = Instant::now();
+    day_func(day)(&input);
+    println!(
+        "Time: {:.3}",
+        (start_time.elapsed().as_millis() as f64) / 1000.0
+    );
+}
+
+fn init_day(day: i32) {
+    let url = format!("https://adventofcode.com/2023/day/{day}/input");
+
+    let session = fs::read_to_string(".session")
+        .expect("Was not able to load the 'session' cookie from .session file. Sign in to AOC pages, get the 'session' cookie and store it in .session file.");
+
+    let name = gitConfig::open_default()
+        .expect("Failed to get git config")
+        .get_string("user.name")
+        .expect("Failed to get 'user.name' from git config");
+
+    let client = Client::new();
+
+    let response = client
+        .get(url)
+        .header(USER_AGENT, name)
+        .header(COOKIE, format!("session={}", session.trim()))
+        .send()
+        .unwrap();
+
+    if !response.status().is_success() {
+        panic!("Failed file download, most likely not authorized.");
+    }
+
+    let file_path = input_file_path(day);
+    let mut file = fs::File::create(file_path.clone()).expect("Failed to create file for input.");
+
+    let mut reader = BufReader::new(response);
+    let mut buffer = Vec::new();
+    reader
+        .read_to_end(&mut buffer)
+        .expect("Failed to read input from web");
+    file.write_all(&buffer)
+        .expect("Failed to write to output file");
+
+    println!("Succeeded to write to file '{}'", file_path);
+}
+
+fn script_dir_path(day: i32) -> String {
+    format!("./{:02}", day)
+}
+
+// Takes the answer from every printed line, ignoring the labels and the F# literal suffixes
+// (e.g. "Part 1: 123L" and "Part 1: 123" are both "123")
+fn extract_answers(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter(|line| !line.starts_with("Time:") && !line.trim().is_empty())
+        .map(|line| {
+            let answer = line.rsplit(':').next().unwrap().trim();
+            answer
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .to_string()
+        })
+        .collect()
+}
+
+fn run_command(command: &mut Command) -> String {
+    let output = command.output().expect("Failed to start the process");
+
+    if !output.status.success() {
+        panic!(
+            "Process failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn compare_day(day: i32) -> bool {
+    let script_dir = script_dir_path(day);
+    let script_input_path = format!("{script_dir}/input.txt");
+
+    // The F# scripts read the input next to them. It is only copied there if it is missing,
+    // a different input already there is left alone and the day is not compared
+    let input = fs::read(input_file_path(day)).expect("Failed to read the input file");
+
+    match fs::read(&script_input_path) {
+        Ok(script_input) if script_input == input => {}
+        Ok(_) => {
+            println!(
+                "Day {}: SKIPPED, {} differs from the runner's input",
+                day, script_input_path
+            );
+            return false;
+        }
+        Err(_) => fs::write(&script_input_path, &input)
+            .expect("Failed to copy the input next to the F# script"),
+    }
+
+    let fsharp_output = run_command(
+        Command::new("dotnet")
+            .arg("fsi")
+            .arg(format!("{script_dir}/script.fsx")),
+    );
+
+    let rust_output = run_command(
+        Command::new(env::current_exe().expect("Failed to get the current executable"))
+            .arg(day.to_string()),
+    );
+
+    let fsharp_answers = extract_answers(&fsharp_output);
+    let rust_answers = extract_answers(&rust_output);
+
+    if fsharp_answers == rust_answers {
+        println!("Day {}: OK {:?}", day, rust_answers);
+        true
+    } else {
+        println!("Day {}: MISMATCH", day);
+        println!("  F#:   {:?}", fsharp_answers);
+        println!("  Rust: {:?}", rust_answers);
+        false
+    }
+}
+
+fn compare_days(days: Vec<i32>) {
+    if Command::new("dotnet").arg("--version").output().is_err() {
+        println!("The 'dotnet' command is not available, it is needed to run the F# scripts.");
+        return;
+    }
+
+    let mismatches: Vec<i32> = days.into_iter().filter(|day| !compare_day(*day)).collect();
+
+    if !mismatches.is_empty() {
+        println!("Mismatching days: {:?}", mismatches);
+        std::process::exit(1);
+    }
+}
+
+fn write_usage() {
+    println!("Incorrect arguments, either day number to run code, init followed by the day number to download input and create source file, or compare followed by the day number (or all) to compare the answers with the F# script.");
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    match args.len() {
+        2 => {
+            if args[1] == "all" {
+                for i in 1..26 {
+                    println!("Day {}:", i);
+                    run_day(i);
+                }
+            } else {
+                run_day(args[1].parse().unwrap());
+            }
+        }
+        3 => match args[1].as_str() {
+            "init" => init_day(args[2].parse().unwrap()),
+            "compare" if args[2] == "all" => compare_days((1..26).collect()),
+            "compare" => compare_days(vec![args[2].parse().unwrap()]),
+            _ => write_usage(),
+        },
+        _ => {
+            write_usage();
+        }
+    }
+}
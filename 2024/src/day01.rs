@@ -1,14 +1,12 @@
+use common::parse::ints_n;
 use itertools::Itertools;
 
 pub fn run(input: &str) {
     let lines: Vec<(i32, i32)> = input
         .lines()
         .map(|line| {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            (
-                parts[0].parse::<i32>().expect("Incorrect number"),
-                parts[1].parse::<i32>().expect("Incorrect number"),
-            )
+            let [left, right] = ints_n::<i32, 2>(line).unwrap();
+            (left, right)
         })
         .collect();
 
@@ -1,11 +1,9 @@
+use common::parse::ints;
+
 pub fn run(input: &str) {
     let numbers = input
         .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|number| number.parse::<i32>().expect("Fail to parse"))
-                .collect::<Vec<_>>()
-        })
+        .map(|line| ints::<i32>(line).unwrap())
         .collect::<Vec<_>>();
 
     let mut safe_count = 0;
@@ -1,54 +1,56 @@
-use regex::Regex;
+use common::parse;
+
+// Every `mul(a,b)` with both numbers written as plain digits, as its position and product
+fn multiplications(memory: &str) -> Vec<(usize, i32)> {
+    let number = |field: &str| {
+        if !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit()) {
+            field.parse::<i32>().ok()
+        } else {
+            None
+        }
+    };
 
-fn part_1(input: &str) {
-    let pattern = r"mul\((\d+),(\d+)\)";
-    let re = Regex::new(pattern).unwrap();
+    memory
+        .match_indices("mul(")
+        .filter_map(|(start, _)| {
+            let end = start + memory[start..].find(')')?;
+            let (a, b) = parse!(&memory[start..=end], "mul({},{})", &str, &str).ok()?;
 
-    let mut sum = 0;
+            Some((start, number(a)? * number(b)?))
+        })
+        .collect()
+}
 
-    for cap in re.captures_iter(input) {
-        let a: i32 = cap[1].parse().expect("Has to be number");
-        let b: i32 = cap[2].parse().expect("Has to be number");
-        sum += a * b;
-    }
+fn part_1(input: &str) {
+    let sum: i32 = multiplications(input)
+        .iter()
+        .map(|(_, product)| product)
+        .sum();
 
     println!("Part 1: {sum}");
 }
 
 fn part_2(input: &str) {
-    let pattern = r"mul\((\d+),(\d+)\)";
-    let re = Regex::new(pattern).unwrap();
+    // Positions where multiplications get enabled or disabled, in order
+    let mut switches: Vec<(usize, bool)> = input
+        .match_indices("do()")
+        .map(|(i, _)| (i, true))
+        .chain(input.match_indices("don't()").map(|(i, _)| (i, false)))
+        .collect();
+    switches.sort_unstable();
 
     let mut sum = 0;
+    let mut enabled = true;
+    let mut next_switch = 0;
 
-    let mut start_index: usize = 0;
-    let mut end_index = input.find("don't()").unwrap_or(input.len() - 1);
-
-    loop {
-        let input_slice = &input[start_index..end_index];
-
-        for cap in re.captures_iter(input_slice) {
-            let a: i32 = cap[1].parse().expect("Has to be number");
-            let b: i32 = cap[2].parse().expect("Has to be number");
-            sum += a * b;
-        }
-
-        start_index = end_index + "don't()".len();
-
-        if start_index < input.len() {
-            match input[start_index..].find("do()") {
-                Some(idx) => {
-                    start_index += idx + "do()".len();
-                    let remaining = &input[start_index..];
-                    end_index =
-                        start_index + remaining.find("don't()").unwrap_or(remaining.len() - 1);
-                }
-                None => start_index = input.len(),
-            }
+    for (position, product) in multiplications(input) {
+        while next_switch < switches.len() && switches[next_switch].0 < position {
+            enabled = switches[next_switch].1;
+            next_switch += 1;
         }
 
-        if start_index >= input.len() {
-            break;
+        if enabled {
+            sum += product;
         }
     }
 
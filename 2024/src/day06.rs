@@ -1,3 +1,7 @@
+use common::bits::{BitGrid, BitSet};
+use common::cycle::brent;
+use common::grid::Grid;
+use common::visualize::{self, Animation};
 use std::collections::HashSet;
 
 #[derive(Eq, Hash, PartialEq, Clone, Copy)]
@@ -10,14 +14,14 @@ enum Direction {
 
 type Coord = (usize, usize);
 type Position = (Coord, Direction);
-type Matrix = Vec<Vec<bool>>;
+type Matrix = BitGrid;
 
 fn parse_input(input: &str) -> (Matrix, Position) {
-    let mut output: Matrix = Vec::new();
+    let mut rows: Vec<BitSet> = Vec::new();
     let mut start: Option<Position> = None;
 
     for (row, line) in input.lines().enumerate() {
-        output.push(
+        rows.push(
             line.chars()
                 .enumerate()
                 .map(|(col, x)| match x {
@@ -44,7 +48,10 @@ fn parse_input(input: &str) -> (Matrix, Position) {
         );
     }
 
-    (output, start.expect("Have not found start"))
+    (
+        rows.into_iter().collect(),
+        start.expect("Have not found start"),
+    )
 }
 
 fn next_pos(map: &Matrix, mut current_pos: Position) -> (bool, Position) {
@@ -53,9 +60,9 @@ fn next_pos(map: &Matrix, mut current_pos: Position) -> (bool, Position) {
     loop {
         is_next_out = match current_pos.1 {
             Direction::Up => current_pos.0 .0 == 0,
-            Direction::Down => current_pos.0 .0 == map.len() - 1,
+            Direction::Down => current_pos.0 .0 == map.height() - 1,
             Direction::Left => current_pos.0 .1 == 0,
-            Direction::Right => current_pos.0 .1 == map[0].len() - 1,
+            Direction::Right => current_pos.0 .1 == map.width() - 1,
         };
 
         if is_next_out {
@@ -69,7 +76,7 @@ fn next_pos(map: &Matrix, mut current_pos: Position) -> (bool, Position) {
             Direction::Right => (current_pos.0 .0, current_pos.0 .1 + 1),
         };
 
-        if map[next_pos.0][next_pos.1] {
+        if map.get(next_pos.0, next_pos.1) {
             current_pos = match current_pos.1 {
                 Direction::Up => (current_pos.0, Direction::Right),
                 Direction::Down => (current_pos.0, Direction::Left),
@@ -104,10 +111,44 @@ fn get_part_1_route(map: &Matrix, start: Position) -> HashSet<Coord> {
     visited_points
 }
 
+// Obstacles in grey, the path so far in yellow and the guard in red
+fn save_route_animation(map: &Matrix, start: Position) {
+    let palette = vec![[20, 20, 30], [120, 120, 120], [230, 200, 60], [220, 40, 40]];
+    let mut animation = Animation::new(palette, 4, 40);
+    let mut frame = Grid::from_fn(map.width(), map.height(), |(r, c)| map.get(r, c) as u8);
+    let mut current_pos = start;
+
+    for step in 0.. {
+        frame[current_pos.0] = 3;
+
+        if step % 20 == 0 {
+            animation.push_frame(&frame, |c| *c);
+        }
+
+        frame[current_pos.0] = 2;
+
+        let (is_next_out, next_pos) = next_pos(map, current_pos);
+        current_pos = next_pos;
+
+        if is_next_out {
+            break;
+        }
+    }
+
+    animation.push_frame(&frame, |c| *c);
+    animation
+        .save("visualize/day06.gif")
+        .expect("Failed to save animation");
+}
+
 fn part_1(input: &str) {
     let (map, start) = parse_input(input);
     let visited_points = get_part_1_route(&map, start);
 
+    if visualize::enabled() {
+        save_route_animation(&map, start);
+    }
+
     println!("Part 1: {}", visited_points.len());
 }
 
@@ -116,32 +157,23 @@ fn part_2(input: &str) {
     let mut variants = 0;
 
     for (r, c) in get_part_1_route(&map, start) {
-        if map[r][c] {
+        if map.get(r, c) {
             continue;
         }
 
-        map[r][c] = true;
-
-        let mut visited_positions: HashSet<Position> = HashSet::new();
-        let mut current_pos = start;
-
-        loop {
-            if visited_positions.contains(&current_pos) {
-                variants += 1;
-                break;
-            }
-
-            visited_positions.insert(current_pos);
+        map.set(r, c, true);
 
-            let (is_next_out, next_pos) = next_pos(&map, current_pos);
-            current_pos = next_pos;
+        // The guard either leaves the map or walks in a loop
+        let guard_loop = brent(&start, |pos| {
+            let (is_next_out, next_pos) = next_pos(&map, *pos);
+            (!is_next_out).then_some(next_pos)
+        });
 
-            if is_next_out {
-                break;
-            }
+        if guard_loop.is_some() {
+            variants += 1;
         }
 
-        map[r][c] = false;
+        map.set(r, c, false);
     }
 
     println!("Part 2: {variants}");
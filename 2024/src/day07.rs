@@ -1,3 +1,5 @@
+use common::parse;
+
 #[derive(Debug)]
 struct InputEntry {
     total: i128,
@@ -8,15 +10,9 @@ fn parse_input(input: &str) -> Vec<InputEntry> {
     input
         .lines()
         .map(|line| {
-            let (left, right) = line.split_once(':').expect("Invalid row");
-
-            InputEntry {
-                total: left.parse().expect("Expected number for total"),
-                numbers: right
-                    .split_whitespace()
-                    .map(|x| x.parse().expect("Expected only numbers"))
-                    .collect(),
-            }
+            let (total, numbers) = parse!(line, "{}: {}", i128, Vec<i128>).unwrap();
+
+            InputEntry { total, numbers }
         })
         .collect()
 }
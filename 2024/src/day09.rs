@@ -1,3 +1,5 @@
+use common::interval::IntervalSet;
+
 #[derive(Debug, Clone)]
 struct FileIndicesRange {
     start: i64,
@@ -128,54 +130,32 @@ fn part_1(input: &str) {
 }
 
 fn part_2(input: &str) {
-    let disk = parse_input(input);
-    let mut final_disk = disk.clone();
+    let mut disk = parse_input(input);
+
+    // Occupied blocks, the free spaces are the gaps between them
+    let mut occupied: IntervalSet<i64> = disk
+        .iter()
+        .map(|file| file.indices[0].start..file.indices[0].start + file.indices[0].length)
+        .collect();
 
-    for file_idx in (1..disk.len()).rev() {
-        let file = &disk[file_idx];
+    for file in disk.iter_mut().skip(1).rev() {
         let start = file.indices[0].start;
         let length = file.indices[0].length;
 
-        let mut moved = 0;
-
-        for place_idx in 1..final_disk.len() {
-            let prev_item = &final_disk[place_idx - 1].indices[0];
-            let next_item = &final_disk[place_idx].indices[0];
-            let space = next_item.start - (prev_item.start + prev_item.length);
-            let space_start = prev_item.start + prev_item.length;
+        let space = occupied
+            .gaps()
+            .take_while(|gap| gap.start < start)
+            .find(|gap| gap.end - gap.start >= length);
 
-            if space_start >= start {
-                break;
-            }
+        if let Some(space) = space {
+            occupied.remove(start..start + length);
+            occupied.insert(space.start..space.start + length);
 
-            if space >= length {
-                final_disk.insert(
-                    place_idx,
-                    File {
-                        idx: file.idx,
-                        indices: vec![FileIndicesRange {
-                            start: prev_item.start + prev_item.length,
-                            length,
-                        }],
-                    },
-                );
-
-                moved = place_idx + 1;
-                break;
-            }
-        }
-
-        if moved > 0 {
-            for cur_idx in moved..final_disk.len() {
-                if final_disk[cur_idx].idx == file.idx {
-                    final_disk.remove(cur_idx);
-                    break;
-                }
-            }
+            file.indices[0].start = space.start;
         }
     }
 
-    let checksum = calculate_checksum_disk(&final_disk);
+    let checksum = calculate_checksum_disk(&disk);
 
     println!("Part 2: {checksum}");
 }
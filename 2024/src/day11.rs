@@ -1,10 +1,8 @@
+use common::parse::ints;
 use std::collections::{HashMap, LinkedList};
 
 fn parse_input(input: &str) -> Vec<i64> {
-    input
-        .split_whitespace()
-        .map(|x| x.parse().expect("Has to be number"))
-        .collect()
+    ints(input).unwrap()
 }
 
 fn count_digits(mut n: i64) -> u32 {
@@ -34,7 +32,7 @@ fn part_1(input: &str) {
             } else {
                 let digits = count_digits(stone);
 
-                if digits % 2 == 0 {
+                if digits.is_multiple_of(2) {
                     let divisor = 10_i64.pow(digits / 2);
                     after_blink_stones.push(stone / divisor);
                     after_blink_stones.push(stone % divisor);
@@ -80,7 +78,7 @@ fn part_2(input: &str) {
         } else {
             let digits = count_digits(stone);
 
-            if digits % 2 == 0 {
+            if digits.is_multiple_of(2) {
                 let divisor = 10_i64.pow(digits / 2);
                 let left_stone = stone / divisor;
                 let right_stone = stone % divisor;
@@ -1,4 +1,6 @@
-use regex::Regex;
+use common::input::blocks;
+use common::linear::solve;
+use common::parse::ints_n;
 
 struct Machine {
     a_button_offset: (i64, i64),
@@ -7,31 +9,15 @@ struct Machine {
 }
 
 fn parse_input(input: &str) -> Vec<Machine> {
-    let button_re = Regex::new(r"X\+(?P<x>\d+), Y\+(?P<y>\d+)").unwrap();
-    let price_re = Regex::new(r"X=(?P<x>\d+), Y=(?P<y>\d+)").unwrap();
-
-    let lines: Vec<&str> = input.lines().collect();
-
-    lines
-        .chunks(4)
-        .map(|chunk| {
-            let first_line = button_re.captures(chunk[0]).unwrap();
-            let second_line = button_re.captures(chunk[1]).unwrap();
-            let third_line = price_re.captures(chunk[2]).unwrap();
+    blocks(input)
+        .iter()
+        .map(|block| {
+            let [ax, ay, bx, by, px, py] = ints_n(block).unwrap();
 
             Machine {
-                a_button_offset: (
-                    first_line["x"].parse().unwrap(),
-                    first_line["y"].parse().unwrap(),
-                ),
-                b_button_offset: (
-                    second_line["x"].parse().unwrap(),
-                    second_line["y"].parse().unwrap(),
-                ),
-                price_location: (
-                    third_line["x"].parse().unwrap(),
-                    third_line["y"].parse().unwrap(),
-                ),
+                a_button_offset: (ax, ay),
+                b_button_offset: (bx, by),
+                price_location: (px, py),
             }
         })
         .collect()
@@ -40,34 +26,30 @@ fn parse_input(input: &str) -> Vec<Machine> {
 fn get_steps(machine: &Machine) -> Option<i64> {
     // a * x_a + b * x_b = X
     // a * y_a + b * y_b = Y
-
-    let x_a = machine.a_button_offset.0;
-    let x_b = machine.b_button_offset.0;
-    let y_a = machine.a_button_offset.1;
-    let y_b = machine.b_button_offset.1;
-    let x = machine.price_location.0;
-    let y = machine.price_location.1;
-
-    // Calculate determinant
-    let det = x_a * y_b - x_b * y_a;
-
-    if det == 0 {
-        // No unique solution exists (determinant is zero).
-        None
-    } else {
-        // Calculate a and b using Cramer's Rule
-        let a_numerator = x * y_b - y * x_b;
-        let b_numerator = y * x_a - x * y_a;
-
-        if a_numerator % det == 0 && b_numerator % det == 0 {
-            let a = a_numerator / det;
-            let b = b_numerator / det;
-            Some(a * 3 + b)
-        } else {
-            // No integer solution exists.
-            None
-        }
-    }
+    let coefficients = vec![
+        vec![
+            machine.a_button_offset.0 as i128,
+            machine.b_button_offset.0 as i128,
+        ],
+        vec![
+            machine.a_button_offset.1 as i128,
+            machine.b_button_offset.1 as i128,
+        ],
+    ];
+    let constants = [
+        machine.price_location.0 as i128,
+        machine.price_location.1 as i128,
+    ];
+
+    // Collinear buttons leave one free variable, the cheapest integer combination is then
+    // picked. Presses stay unbounded only when neither button moves the claw, which can
+    // then only win a prize at the start, for no tokens.
+    let tokens = match solve(&coefficients, &constants)?.min_non_negative_integer(&[3, 1]) {
+        Ok(solution) => solution?.0,
+        Err(_) => 0,
+    };
+
+    Some(tokens as i64)
 }
 
 fn part_1(input: &str) {
@@ -1,4 +1,8 @@
-use regex::Regex;
+use common::bits::{BitGrid, BitSet};
+use common::grid::Grid;
+use common::math::crt;
+use common::parse::ints_n;
+use common::visualize::{self, Animation};
 
 #[derive(Debug, Clone, Copy)]
 struct Coord {
@@ -13,22 +17,14 @@ struct Robot {
 }
 
 fn parse_input(input: &str) -> Vec<Robot> {
-    let re = Regex::new(r"p=(?P<px>-?\d+),(?P<py>-?\d+) v=(?P<vx>-?\d+),(?P<vy>-?\d+)").unwrap();
-
     input
         .lines()
         .map(|line| {
-            let capture = re.captures(line).unwrap();
+            let [px, py, vx, vy] = ints_n(line).unwrap();
 
             Robot {
-                p: Coord {
-                    x: capture["px"].parse().unwrap(),
-                    y: capture["py"].parse().unwrap(),
-                },
-                v: Coord {
-                    x: capture["vx"].parse().unwrap(),
-                    y: capture["vy"].parse().unwrap(),
-                },
+                p: Coord { x: px, y: py },
+                v: Coord { x: vx, y: vy },
             }
         })
         .collect()
@@ -77,85 +73,108 @@ fn part_1(input: &str) {
     println!("Part 1: {safety_factor}");
 }
 
-fn print_robots(robots: &Vec<Robot>, steps: i128, width: i128, height: i128) -> bool {
-    let mut map: Vec<Vec<bool>> = (0..height)
-        .map(|_| (0..width).map(|_| false).collect())
-        .collect();
+fn print_robots(robots: &Vec<Robot>, steps: i128, width: i128, height: i128) {
+    let mut map = BitGrid::new(width as usize, height as usize);
 
     for robot in robots {
-        map[robot.p.y as usize][robot.p.x as usize] = true;
+        map.set(robot.p.y as usize, robot.p.x as usize, true);
     }
 
-    let mut can_be_tree = false;
-
-    for y in 0..map.len() - 5 {
-        for x in 0..map[0].len() - 5 {
-            let mut found_block = true;
-
-            for dy in 0..5 {
-                for dx in 0..5 {
-                    if !map[y + dy][x + dx] {
-                        found_block = false;
-                        break;
-                    }
-                }
+    // Bit x of a run is on when the row has robots on all of x..x + 5
+    let runs: Vec<BitSet> = map
+        .rows()
+        .iter()
+        .map(|row| (1..5).fold(row.clone(), |run, shift| &run & &(row >> shift)))
+        .collect();
 
-                if !found_block {
-                    break;
-                }
-            }
+    let can_be_tree = runs.windows(5).any(|block| {
+        block[1..]
+            .iter()
+            .fold(block[0].clone(), |all, run| &all & run)
+            .any()
+    });
 
-            if found_block {
-                can_be_tree = true;
-                break;
-            }
-        }
+    if can_be_tree {
+        print!("{}", map.render('X', '.'));
 
-        if can_be_tree {
-            break;
-        }
+        println!();
+        println!("{steps}");
     }
+}
 
-    if can_be_tree {
-        for row in map {
-            for c in row {
-                if c {
-                    print!("X");
-                } else {
-                    print!(".");
-                }
-            }
+// The robots coming together over the last steps before the picture, which is held for a
+// while at the end
+fn save_animation(robots: &[Robot], steps: i128, width: i128, height: i128) {
+    let mut animation = Animation::new(vec![[10, 10, 30], [40, 200, 80]], 4, 100);
+
+    for step in (steps - 40).max(0)..=steps + 10 {
+        let mut frame = Grid::new(width as usize, height as usize, 0);
 
-            println!();
+        for robot in robots {
+            let p = simulate_robot(robot, step.min(steps), width, height).p;
+            frame[(p.y as usize, p.x as usize)] = 1;
         }
 
-        println!();
-        println!("{steps}");
+        animation.push_frame(&frame, |c| *c);
     }
 
-    can_be_tree
+    animation
+        .save("visualize/day14.gif")
+        .expect("Failed to save animation");
+}
+
+// Sum of squared deviations from the mean (scaled by the count to stay in integers)
+fn spread(values: impl Iterator<Item = i128>) -> i128 {
+    let values: Vec<i128> = values.collect();
+    let count = values.len() as i128;
+    let sum: i128 = values.iter().sum();
+    let sum_of_squares: i128 = values.iter().map(|v| v * v).sum();
+
+    count * sum_of_squares - sum * sum
 }
 
 fn part_2(input: &str) {
     let robots = parse_input(input);
     let width = 101;
     let height = 103;
-    let mut steps = 0;
 
-    loop {
-        let after_simulation: Vec<_> = robots
-            .iter()
-            .map(|r| simulate_robot(r, steps, width, height))
-            .collect();
+    // X coordinates repeat every `width` steps and Y coordinates every `height` steps.
+    // The picture appears when robots are clustered in both, so the best step for each
+    // axis is found independently and combined with the Chinese remainder theorem.
+    let best_x_step = (0..width)
+        .min_by_key(|steps| {
+            spread(
+                robots
+                    .iter()
+                    .map(|r| simulate_robot(r, *steps, width, height).p.x),
+            )
+        })
+        .unwrap();
+
+    let best_y_step = (0..height)
+        .min_by_key(|steps| {
+            spread(
+                robots
+                    .iter()
+                    .map(|r| simulate_robot(r, *steps, width, height).p.y),
+            )
+        })
+        .unwrap();
 
-        let can_be_tree = print_robots(&after_simulation, steps, width, height);
+    let (steps, _) = crt(&[(best_x_step, width), (best_y_step, height)]).unwrap();
 
-        if can_be_tree {
-            break;
-        }
+    let after_simulation: Vec<_> = robots
+        .iter()
+        .map(|r| simulate_robot(r, steps, width, height))
+        .collect();
+
+    print_robots(&after_simulation, steps, width, height);
 
-        steps += 1;
+    if visualize::enabled() {
+        save_animation(&robots, steps, width, height);
     }
+
+    println!("Part 2: {steps}");
 }
 
 pub fn run(input: &str) {
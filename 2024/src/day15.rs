@@ -1,77 +1,50 @@
-use std::collections::LinkedList;
+use common::grid::{Direction, Grid};
+use common::input::sections;
+use common::sokoban::{Replay, Warehouse};
+use common::visualize::{self, Animation};
+use std::sync::OnceLock;
 
-use itertools::Itertools;
+static PRINTED_STEPS: OnceLock<Vec<usize>> = OnceLock::new();
 
-#[derive(Debug, Clone, Copy)]
-enum Field {
-    Robot,
-    Nothing,
-    Box,
-    Wall,
+/// Prints the warehouse after each of `steps` moves and what changed from each of them to
+/// the next one, for a `--day15-steps=10,20,30` command line flag.
+pub fn print_steps_at(steps: Vec<usize>) {
+    PRINTED_STEPS
+        .set(steps)
+        .expect("The printed steps can only be set once");
 }
 
-enum Field2 {
-    Robot,
-    Nothing,
-    BoxR,
-    BoxL,
-    Wall,
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-type Coord = (usize, usize);
-
-struct State {
-    fields: Vec<Vec<Field>>,
-    robot: Coord,
-    instructions: LinkedList<Direction>,
-}
-
-struct State2 {
-    fields: Vec<Vec<Field2>>,
-    robot: Coord,
-    instructions: LinkedList<Direction>,
-}
-
-fn parse_input(input: &str) -> State {
-    let map_line_count = input.lines().find_position(|x| x.is_empty()).unwrap().0;
+// Every tile of the map becomes `scale` cells of the warehouse, a box one object over all
+// of them and the robot one object on the first
+fn parse_input(input: &str, scale: usize) -> Replay {
+    let [map_section, instructions_section] = sections(input);
 
-    let fields: Vec<Vec<Field>> = input
+    let map: Vec<Vec<char>> = map_section
         .lines()
-        .take(map_line_count)
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '#' => Field::Wall,
-                    'O' => Field::Box,
-                    '@' => Field::Robot,
-                    '.' => Field::Nothing,
-                    _ => panic!("Unknown char {c}"),
-                })
-                .collect()
-        })
+        .map(|line| line.chars().collect())
         .collect();
 
-    let mut robot: Option<Coord> = None;
+    let mut warehouse = Warehouse::new(map[0].len() * scale, map.len());
+    let mut robot: Option<usize> = None;
+
+    for (x, row) in map.iter().enumerate() {
+        for (y, c) in row.iter().enumerate() {
+            let cells: Vec<_> = (0..scale).map(|i| (x, y * scale + i)).collect();
 
-    for (x, row) in fields.iter().enumerate() {
-        for (y, cell) in row.iter().enumerate() {
-            if matches!(cell, Field::Robot) {
-                robot = Some((x, y));
+            match c {
+                '#' => cells.into_iter().for_each(|cell| warehouse.add_wall(cell)),
+                'O' => {
+                    warehouse.add_object(cells);
+                }
+                '@' => robot = Some(warehouse.add_object(vec![cells[0]])),
+                '.' => {}
+                _ => panic!("Unknown char {c}"),
             }
         }
     }
 
-    let instruction_lines = input.lines().skip(map_line_count + 1);
-
-    let instructions: LinkedList<_> = instruction_lines
+    let instructions = instructions_section
+        .lines()
         .flat_map(|line| {
             line.chars().map(|c| match c {
                 '>' => Direction::Right,
@@ -83,212 +56,119 @@ fn parse_input(input: &str) -> State {
         })
         .collect();
 
-    State {
-        fields,
-        robot: robot.unwrap(),
-        instructions,
-    }
+    Replay::new(warehouse, robot.unwrap(), instructions)
 }
 
-fn try_move_field(state: &mut State, (x, y): Coord, direction: Direction) -> Option<Coord> {
-    match state.fields[x][y] {
-        Field::Robot | Field::Box => {
-            let next_position = match direction {
-                Direction::Up => (x - 1, y),
-                Direction::Down => (x + 1, y),
-                Direction::Left => (x, y - 1),
-                Direction::Right => (x, y + 1),
-            };
-
-            match try_move_field(state, next_position, direction) {
-                Some(_) => {
-                    state.fields[next_position.0][next_position.1] = state.fields[x][y];
-                    state.fields[x][y] = Field::Nothing;
-                    Some(next_position)
-                }
-                None => None,
-            }
+fn render(replay: &Replay) -> String {
+    let warehouse = replay.warehouse();
+
+    warehouse.render('#', '.', |object, pos| {
+        let cells = warehouse.cells(object);
+
+        if object == replay.pusher() {
+            '@'
+        } else if cells.len() == 1 {
+            'O'
+        } else if pos == cells[0] {
+            '['
+        } else {
+            ']'
         }
-        Field::Nothing => Some((x, y)),
-        Field::Wall => None,
-    }
+    })
 }
 
-fn perform_move(state: &mut State) {
-    let instruction = state.instructions.pop_front().unwrap();
-    let robot_move = try_move_field(state, state.robot, instruction);
-    if let Some(new_coord) = robot_move {
-        state.robot = new_coord;
+fn describe(replay: &Replay, object: Option<usize>) -> String {
+    match object {
+        Some(o) if o == replay.pusher() => String::from("robot"),
+        Some(o) => format!("box {o}"),
+        None => String::from("nothing"),
     }
 }
 
-fn calculate_coords<T, X>(fields: &[Vec<T>], matcher: X) -> usize
-where
-    X: Fn(&T) -> bool,
-{
-    let mut total: usize = 0;
+fn print_steps(replay: &mut Replay) {
+    let Some(steps) = PRINTED_STEPS.get() else {
+        return;
+    };
 
-    for (x, row) in fields.iter().enumerate() {
-        for (y, cell) in row.iter().enumerate() {
-            if matcher(cell) {
-                total += 100 * x + y;
-            }
-        }
+    for step in steps {
+        replay.seek(*step);
+        println!("Step {}:", replay.step());
+        println!("{}", render(replay));
     }
 
-    total
-}
+    for pair in steps.windows(2) {
+        println!("Changes from step {} to {}:", pair[0], pair[1]);
 
-fn calculate_coords_1(state: &State) -> usize {
-    calculate_coords(&state.fields, |x| matches!(x, Field::Box))
+        for change in replay.diff(pair[0], pair[1]) {
+            println!(
+                "{:?}: {} -> {}",
+                change.pos,
+                describe(replay, change.before),
+                describe(replay, change.after)
+            );
+        }
+    }
 }
 
-fn part_1(input: &str) {
-    let mut state = parse_input(input);
+// Walls in grey, boxes in brown and the robot in red, with about 300 frames over all moves
+fn save_animation(replay: &mut Replay, path: &str) {
+    let palette = vec![[20, 20, 30], [120, 120, 120], [170, 110, 50], [220, 40, 40]];
+    let mut animation = Animation::new(palette, 4, 40);
+    let stride = (replay.len() / 300).max(1);
 
-    while !state.instructions.is_empty() {
-        perform_move(&mut state);
-    }
+    for step in (0..replay.len()).step_by(stride).chain([replay.len()]) {
+        replay.seek(step);
 
-    let outcome = calculate_coords_1(&state);
+        let warehouse = replay.warehouse();
+        let frame = Grid::from_fn(warehouse.width(), warehouse.height(), |pos| match warehouse
+            .object_at(pos)
+        {
+            Some(o) if o == replay.pusher() => 3,
+            Some(_) => 2,
+            None => warehouse.is_wall(pos) as u8,
+        });
 
-    println!("Part 1: {outcome}");
+        animation.push_frame(&frame, |c| *c);
+    }
+
+    animation.save(path).expect("Failed to save animation");
 }
 
-fn expand_state(input: &State) -> State2 {
-    let fields = input
-        .fields
-        .iter()
-        .map(|row| {
-            row.iter()
-                .flat_map(|field| match field {
-                    Field::Robot => vec![Field2::Robot, Field2::Nothing],
-                    Field::Nothing => vec![Field2::Nothing, Field2::Nothing],
-                    Field::Box => vec![Field2::BoxL, Field2::BoxR],
-                    Field::Wall => vec![Field2::Wall, Field2::Wall],
-                })
-                .collect()
-        })
-        .collect();
+// Boxes are measured from their top left cell
+fn calculate_coords(replay: &Replay) -> usize {
+    let warehouse = replay.warehouse();
 
-    State2 {
-        fields,
-        instructions: input.instructions.clone(),
-        robot: (input.robot.0, input.robot.1 * 2),
-    }
+    (0..warehouse.object_count())
+        .filter(|object| *object != replay.pusher())
+        .map(|object| {
+            let (x, y) = warehouse.cells(object).iter().min().unwrap();
+            100 * x + y
+        })
+        .sum()
 }
 
-fn move_field_2(state: &mut State2, (x, y): Coord, direction: Direction) -> Coord {
-    let (nx, ny) = match direction {
-        Direction::Up => (x - 1, y),
-        Direction::Down => (x + 1, y),
-        Direction::Left => (x, y - 1),
-        Direction::Right => (x, y + 1),
-    };
+fn calculate_part(input: &str, scale: usize) -> usize {
+    let mut replay = parse_input(input, scale);
 
-    match state.fields[x][y] {
-        Field2::Robot => {
-            move_field_2(state, (nx, ny), direction);
-            state.fields[nx][ny] = Field2::Robot;
-            state.fields[x][y] = Field2::Nothing;
-            (nx, ny)
-        }
-        Field2::Nothing => (nx, ny),
-        Field2::BoxR => match direction {
-            Direction::Left | Direction::Right => {
-                move_field_2(state, (nx, ny), direction);
-                state.fields[nx][ny] = Field2::BoxR;
-                state.fields[x][y] = Field2::Nothing;
-                (nx, ny)
-            }
-            Direction::Up | Direction::Down => {
-                move_field_2(state, (nx, ny), direction);
-                state.fields[nx][ny] = Field2::BoxR;
-                state.fields[x][y] = Field2::Nothing;
-                move_field_2(state, (x, y - 1), direction);
-                (nx, ny)
-            }
-        },
-        Field2::BoxL => match direction {
-            Direction::Left | Direction::Right => {
-                move_field_2(state, (nx, ny), direction);
-                state.fields[nx][ny] = Field2::BoxL;
-                state.fields[x][y] = Field2::Nothing;
-                (nx, ny)
-            }
-            Direction::Up | Direction::Down => {
-                move_field_2(state, (nx, ny), direction);
-                state.fields[nx][ny] = Field2::BoxL;
-                state.fields[x][y] = Field2::Nothing;
-                move_field_2(state, (x, y + 1), direction);
-                (nx, ny)
-            }
-        },
-        Field2::Wall => panic!("Should not be called"),
-    }
-}
+    print_steps(&mut replay);
 
-fn can_move_field_2(state: &State2, (x, y): Coord, direction: Direction) -> bool {
-    match state.fields[x][y] {
-        Field2::Nothing => true,
-        Field2::Wall => false,
-        Field2::Robot => {
-            let next_position = match direction {
-                Direction::Up => (x - 1, y),
-                Direction::Down => (x + 1, y),
-                Direction::Left => (x, y - 1),
-                Direction::Right => (x, y + 1),
-            };
-
-            can_move_field_2(state, next_position, direction)
-        }
-        Field2::BoxR => {
-            let next_positions = match direction {
-                Direction::Up => vec![(x - 1, y), (x - 1, y - 1)],
-                Direction::Down => vec![(x + 1, y), (x + 1, y - 1)],
-                Direction::Left => vec![(x, y - 1)],
-                Direction::Right => vec![(x, y + 1)],
-            };
-
-            next_positions
-                .iter()
-                .all(|pos| can_move_field_2(state, *pos, direction))
-        }
-        Field2::BoxL => {
-            let next_positions = match direction {
-                Direction::Up => vec![(x - 1, y), (x - 1, y + 1)],
-                Direction::Down => vec![(x + 1, y), (x + 1, y + 1)],
-                Direction::Left => vec![(x, y - 1)],
-                Direction::Right => vec![(x, y + 1)],
-            };
-
-            next_positions
-                .iter()
-                .all(|pos| can_move_field_2(state, *pos, direction))
-        }
+    if visualize::enabled() {
+        save_animation(&mut replay, &format!("visualize/day15_part{scale}.gif"));
     }
-}
 
-fn perform_move_2(state: &mut State2) {
-    let instruction = state.instructions.pop_front().unwrap();
+    replay.seek(replay.len());
 
-    if can_move_field_2(state, state.robot, instruction) {
-        state.robot = move_field_2(state, state.robot, instruction);
-    }
+    calculate_coords(&replay)
 }
 
-fn calculate_coords_2(state: &State2) -> usize {
-    calculate_coords(&state.fields, |x| matches!(x, Field2::BoxL))
+fn part_1(input: &str) {
+    let outcome = calculate_part(input, 1);
+
+    println!("Part 1: {outcome}");
 }
 
 fn part_2(input: &str) {
-    let mut state = expand_state(&parse_input(input));
-
-    while !state.instructions.is_empty() {
-        perform_move_2(&mut state);
-    }
-
-    let outcome = calculate_coords_2(&state);
+    let outcome = calculate_part(input, 2);
 
     println!("Part 2: {outcome}");
 }
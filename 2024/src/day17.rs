@@ -1,4 +1,5 @@
-use regex::Regex;
+use common::input::sections;
+use common::parse::{ints, ints_n};
 
 #[derive(Debug, Clone, Copy)]
 struct MachineState {
@@ -17,35 +18,12 @@ struct Machine {
 }
 
 fn parse_input(input: &str) -> Machine {
-    let lines: Vec<_> = input.lines().collect();
-    let register_re = Regex::new(r"Register .: (?P<value>-?\d+)").unwrap();
-    let register_a = register_re.captures(lines[0]).unwrap()["value"]
-        .parse()
-        .unwrap();
-    let register_b = register_re.captures(lines[1]).unwrap()["value"]
-        .parse()
-        .unwrap();
-    let register_c = register_re.captures(lines[2]).unwrap()["value"]
-        .parse()
-        .unwrap();
-
-    let instructions = lines[4]
-        .split_once(":")
-        .unwrap()
-        .1
-        .trim()
-        .split(",")
-        .map(|num| num.parse().unwrap())
-        .collect();
+    let [registers, program] = sections(input);
+    let [a, b, c] = ints_n(registers).unwrap();
 
     Machine {
-        state: MachineState {
-            a: register_a,
-            b: register_b,
-            c: register_c,
-            pos: 0,
-        },
-        instructions,
+        state: MachineState { a, b, c, pos: 0 },
+        instructions: ints(program).unwrap(),
     }
 }
 
@@ -1,49 +1,48 @@
-use std::collections::{HashSet, LinkedList};
+use common::bits::BitGrid;
+use common::parse;
+use std::collections::LinkedList;
 
-type Map = Vec<Vec<bool>>;
+type Map = BitGrid;
 
 type Coord = (usize, usize);
 
 fn parse_input(input: &str) -> Vec<Coord> {
     input
         .lines()
-        .map(|line| {
-            let (xs, ys) = line.split_once(",").unwrap();
-            (xs.parse().unwrap(), ys.parse().unwrap())
-        })
+        .map(|line| parse!(line, "{},{}", usize, usize).unwrap())
         .collect()
 }
 
 fn create_empty_map() -> Map {
-    (0..71).map(|_| (0..71).map(|_| false).collect()).collect()
+    BitGrid::new(71, 71)
 }
 
 fn get_path_length(falling_bytes: &[Coord], bytes_to_fall: usize) -> Option<usize> {
     let mut map = create_empty_map();
 
     for fallen_byte in falling_bytes.iter().take(bytes_to_fall) {
-        map[fallen_byte.1][fallen_byte.0] = true;
+        map.set(fallen_byte.1, fallen_byte.0, true);
     }
 
     let mut queue: LinkedList<(Coord, usize)> = LinkedList::new();
-    let mut visited: HashSet<Coord> = HashSet::new();
+    let mut visited = create_empty_map();
     let mut shortest_path = None;
 
     queue.push_back(((0, 0), 0));
 
     while let Some((coord, path_length)) = queue.pop_front() {
-        if coord == (map.len() - 1, map[0].len() - 1) {
+        if coord == (map.width() - 1, map.height() - 1) {
             shortest_path = Some(path_length);
             break;
         }
 
-        if visited.contains(&coord) {
+        if visited.get(coord.1, coord.0) {
             continue;
         } else {
-            visited.insert(coord);
+            visited.set(coord.1, coord.0, true);
         }
 
-        if map[coord.1][coord.0] {
+        if map.get(coord.1, coord.0) {
             continue;
         }
 
@@ -52,13 +51,13 @@ fn get_path_length(falling_bytes: &[Coord], bytes_to_fall: usize) -> Option<usiz
         if coord.0 > 0 {
             next_coords.push((coord.0 - 1, coord.1));
         }
-        if coord.0 < map.len() - 1 {
+        if coord.0 < map.width() - 1 {
             next_coords.push((coord.0 + 1, coord.1));
         }
         if coord.1 > 0 {
             next_coords.push((coord.0, coord.1 - 1));
         }
-        if coord.1 < map[0].len() - 1 {
+        if coord.1 < map.height() - 1 {
             next_coords.push((coord.0, coord.1 + 1));
         }
 
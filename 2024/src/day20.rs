@@ -1,11 +1,12 @@
+use common::bits::{BitGrid, BitSet};
 use std::collections::{HashMap, HashSet};
 
 type Coord = (usize, usize);
 
-type Map = Vec<Vec<bool>>;
+type Map = BitGrid;
 
 fn parse_input(input: &str) -> (Map, Coord, Coord) {
-    let mut map: Map = Vec::new();
+    let mut rows: Vec<BitSet> = Vec::new();
     let mut start: Option<Coord> = None;
     let mut end: Option<Coord> = None;
 
@@ -32,10 +33,10 @@ fn parse_input(input: &str) -> (Map, Coord, Coord) {
             }
         }
 
-        map.push(cur_row);
+        rows.push(cur_row.into_iter().collect());
     }
 
-    (map, start.unwrap(), end.unwrap())
+    (rows.into_iter().collect(), start.unwrap(), end.unwrap())
 }
 
 fn get_next_steps((x, y): Coord, map: &Map) -> Vec<Coord> {
@@ -43,13 +44,13 @@ fn get_next_steps((x, y): Coord, map: &Map) -> Vec<Coord> {
     if x > 0 {
         next_coords.push((x - 1, y));
     }
-    if x < map.len() - 1 {
+    if x < map.height() - 1 {
         next_coords.push((x + 1, y));
     }
     if y > 0 {
         next_coords.push((x, y - 1));
     }
-    if y < map[0].len() - 1 {
+    if y < map.width() - 1 {
         next_coords.push((x, y + 1));
     }
     next_coords
@@ -65,13 +66,13 @@ fn get_next_steps_with_cheats((x, y): Coord, max_cheats: usize, map: &Map) -> Ha
             if x >= x_diff && y >= y_diff {
                 output.insert((x - x_diff, y - y_diff));
             }
-            if x >= x_diff && y + y_diff < map[0].len() {
+            if x >= x_diff && y + y_diff < map.width() {
                 output.insert((x - x_diff, y + y_diff));
             }
-            if x + x_diff < map.len() && y >= y_diff {
+            if x + x_diff < map.height() && y >= y_diff {
                 output.insert((x + x_diff, y - y_diff));
             }
-            if x + x_diff < map.len() && y + y_diff < map[0].len() {
+            if x + x_diff < map.height() && y + y_diff < map.width() {
                 output.insert((x + x_diff, y + y_diff));
             }
         }
@@ -93,7 +94,7 @@ fn create_shortest_no_cheating_paths(start: Coord, map: &Map) -> HashMap<Coord,
 
         for next_option in get_next_steps(cur_pos, map) {
             // Skip if it is wall
-            if map[next_option.0][next_option.1] {
+            if map.get(next_option.0, next_option.1) {
                 continue;
             }
 
@@ -139,7 +140,7 @@ fn calculate_part(input: &str, cheating_length: usize) -> i32 {
         let &distance_to_cheat_start = no_cheat_map.get(&cheat_start).unwrap();
 
         for cheat_end in get_next_steps_with_cheats(cheat_start, cheating_length, &map) {
-            if map[cheat_end.0][cheat_end.1] {
+            if map.get(cheat_end.0, cheat_end.1) {
                 continue;
             }
 
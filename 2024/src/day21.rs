@@ -1,8 +1,11 @@
-use cached::proc_macro::cached;
+use common::memo::Memo;
 
 type Coord = (usize, usize);
 type Keyboard = [[char; 3]];
 
+// Shortest lengths keyed by (from, to, is_numeric, transforms)
+type PathLengths = Memo<(char, char, bool, usize), usize>;
+
 fn find_symbol(s: char, keyboard: &Keyboard) -> Coord {
     let mut pos = None;
 
@@ -69,7 +72,12 @@ static NUMERIC_KEYBOARD: [[char; 3]; 4] = [
 
 static MOVEMENT_KEYBOARD: [[char; 3]; 2] = [['#', '^', 'A'], ['<', 'v', '>']];
 
-fn get_shortest_sequence_length(input: String, is_numeric: bool, transforms: usize) -> usize {
+fn get_shortest_sequence_length(
+    input: &str,
+    is_numeric: bool,
+    transforms: usize,
+    memo: &mut PathLengths,
+) -> usize {
     if transforms == 0 {
         return input.len();
     }
@@ -78,15 +86,33 @@ fn get_shortest_sequence_length(input: String, is_numeric: bool, transforms: usi
     let mut shortest_output_length = 0;
 
     for input_c in input.chars() {
-        shortest_output_length += get_shortest_path_length(last_c, input_c, is_numeric, transforms);
+        shortest_output_length +=
+            get_shortest_path_length(last_c, input_c, is_numeric, transforms, memo);
         last_c = input_c;
     }
 
     shortest_output_length
 }
 
-#[cached]
-fn get_shortest_path_length(from: char, to: char, is_numeric: bool, transforms: usize) -> usize {
+fn get_shortest_path_length(
+    from: char,
+    to: char,
+    is_numeric: bool,
+    transforms: usize,
+    memo: &mut PathLengths,
+) -> usize {
+    memo.get((from, to, is_numeric, transforms), |memo| {
+        compute_shortest_path_length(from, to, is_numeric, transforms, memo)
+    })
+}
+
+fn compute_shortest_path_length(
+    from: char,
+    to: char,
+    is_numeric: bool,
+    transforms: usize,
+    memo: &mut PathLengths,
+) -> usize {
     let keyboard: &[[char; 3]] = match is_numeric {
         true => &NUMERIC_KEYBOARD,
         false => &MOVEMENT_KEYBOARD,
@@ -98,7 +124,8 @@ fn get_shortest_path_length(from: char, to: char, is_numeric: bool, transforms:
     let mut shortest_length = None;
 
     for variant in find_all_shortest_paths_to_symbol(from_pos, to_pos, keyboard) {
-        let variant_shortest_length = get_shortest_sequence_length(variant, false, transforms - 1);
+        let variant_shortest_length =
+            get_shortest_sequence_length(&variant, false, transforms - 1, memo);
         match shortest_length {
             Some(x) if x <= variant_shortest_length => {}
             _ => {
@@ -111,11 +138,12 @@ fn get_shortest_path_length(from: char, to: char, is_numeric: bool, transforms:
 }
 
 fn calculate_part(input: &str, transforms: usize) -> usize {
+    let mut memo = PathLengths::new();
     let mut total = 0;
 
     for line in input.lines() {
         let numerical: usize = line[0..line.len() - 1].parse().unwrap();
-        let shortest_sequence = get_shortest_sequence_length(String::from(line), true, transforms);
+        let shortest_sequence = get_shortest_sequence_length(line, true, transforms, &mut memo);
         total += numerical * shortest_sequence;
     }
 
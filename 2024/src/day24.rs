@@ -1,3 +1,5 @@
+use common::input::sections;
+use common::parse;
 use std::collections::HashMap;
 
 use itertools::Itertools;
@@ -87,26 +89,24 @@ fn parse_input(input: &str) -> Input<'_> {
         operations: HashMap::new(),
     };
 
-    for line in input.lines() {
-        if line.contains(":") {
-            let (wire, raw_val) = line.split_once(":").unwrap();
-            let num_val = raw_val.trim().parse::<u8>().unwrap();
-            output.inputs.insert(wire, num_val == 1);
-        } else if line.contains("->") {
-            let parts: Vec<_> = line.split_whitespace().collect();
-            let l = parts[0];
-            let r = parts[2];
-            let o = parts[4];
-
-            let op = match parts[1] {
-                "AND" => Oper::And,
-                "OR" => Oper::Or,
-                "XOR" => Oper::Xor,
-                _ => panic!("Unexpected operator"),
-            };
-
-            output.operations.insert(o, Gate { l, r, op });
-        }
+    let [inputs_section, gates_section] = sections(input);
+
+    for line in inputs_section.lines() {
+        let (wire, num_val) = parse!(line, "{}: {}", &str, u8).unwrap();
+        output.inputs.insert(wire, num_val == 1);
+    }
+
+    for line in gates_section.lines() {
+        let (l, op, r, o) = parse!(line, "{} {} {} -> {}", &str, &str, &str, &str).unwrap();
+
+        let op = match op {
+            "AND" => Oper::And,
+            "OR" => Oper::Or,
+            "XOR" => Oper::Xor,
+            _ => panic!("Unexpected operator"),
+        };
+
+        output.operations.insert(o, Gate { l, r, op });
     }
 
     output
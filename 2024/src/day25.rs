@@ -1,3 +1,5 @@
+use common::input::blocks;
+
 type Heights = Vec<Vec<usize>>;
 
 #[derive(Debug, Clone)]
@@ -59,18 +61,11 @@ fn parse_block(input: &Vec<&str>, output: &mut Option<Input>) -> Input {
 
 fn parse_input(input: &str) -> Input {
     let mut output = None;
-    let mut lines_block = Vec::new();
-
-    for line in input.lines() {
-        if line.is_empty() {
-            output = Some(parse_block(&lines_block, &mut output));
-            lines_block.clear();
-        } else {
-            lines_block.push(line);
-        }
-    }
 
-    output = Some(parse_block(&lines_block, &mut output));
+    for block in blocks(input) {
+        let lines_block: Vec<&str> = block.lines().collect();
+        output = Some(parse_block(&lines_block, &mut output));
+    }
 
     output.unwrap()
 }
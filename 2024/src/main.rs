@@ -1,3 +1,5 @@
+use common::input::normalize;
+use common::visualize;
 use git2::Config as gitConfig;
 use reqwest::blocking::Client;
 use reqwest::header::{COOKIE, USER_AGENT};
@@ -70,7 +72,7 @@ fn input_file_path(day: i32) -> String {
 
 fn run_day(day: i32) {
     let path = input_file_path(day);
-    let input = fs::read_to_string(&path).unwrap();
+    let input = normalize(&fs::read_to_string(&path).unwrap());
 
     let start_time = Instant::now();
     day_func(day)(&input);
@@ -119,11 +121,33 @@ fn init_day(day: i32) {
 }
 
 fn write_usage() {
-    println!("Incorrect arguments, either day number to run code, or init followed by the day number to download input and create source file.");
+    println!("Incorrect arguments, either day number to run code, or init followed by the day number to download input and create source file. Add --visualize to save animations of the days that have them, or --day15-steps=10,20,30 to print the warehouse of day 15 after those moves.");
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // Solutions that can be animated save their animations when asked to
+    if let Some(pos) = args.iter().position(|arg| arg == "--visualize") {
+        args.remove(pos);
+        visualize::enable();
+    }
+
+    // For debugging day 15, e.g. --day15-steps=10,20,30
+    if let Some(pos) = args
+        .iter()
+        .position(|arg| arg.starts_with("--day15-steps="))
+    {
+        let arg = args.remove(pos);
+        let steps = arg["--day15-steps=".len()..]
+            .split(',')
+            .map(str::trim)
+            .filter(|step| !step.is_empty())
+            .map(|step| step.parse().expect("--day15-steps takes step numbers"))
+            .collect();
+
+        day15::print_steps_at(steps);
+    }
 
     match args.len() {
         2 => {
@@ -1,11 +1,10 @@
+use common::parse;
+
 pub fn run(input: &str) {
-    let ranges = input.trim().split(",").map(|range| {
-        let parts: Vec<&str> = range.split("-").collect();
-        (
-            parts[0].parse::<i64>().expect("Incorrect number"),
-            parts[1].parse::<i64>().expect("Incorrect number"),
-        )
-    });
+    let ranges = input
+        .trim()
+        .split(",")
+        .map(|range| parse!(range, "{}-{}", i64, i64).unwrap());
 
     let mut part_1 = 0;
     let mut part_2 = 0;
@@ -1,3 +1,5 @@
+use common::grid::Grid;
+use common::visualize::{self, Animation};
 use std::collections::{HashMap, HashSet};
 
 type Location = (usize, usize);
@@ -40,7 +42,17 @@ pub fn run(input: &str) {
     let mut rays: HashSet<usize> = HashSet::new();
     rays.insert(starting_point.0);
 
-    for row in matrix.iter().skip(starting_point.1 + 1) {
+    // Splitters in grey and beams in cyan, with a frame for every row the beams reach
+    let mut animation = visualize::enabled().then(|| {
+        let palette = vec![[10, 10, 30], [120, 120, 120], [80, 220, 240]];
+        let frame = Grid::from_fn(matrix[0].len(), matrix.len(), |(y, x)| {
+            matches!(matrix[y][x], Place::Splitter) as u8
+        });
+
+        (Animation::new(palette, 4, 60), frame)
+    });
+
+    for (y, row) in matrix.iter().enumerate().skip(starting_point.1 + 1) {
         let mut new_rays: HashSet<usize> = HashSet::new();
 
         for r in rays.iter() {
@@ -57,6 +69,20 @@ pub fn run(input: &str) {
         }
 
         rays = new_rays;
+
+        if let Some((animation, frame)) = animation.as_mut() {
+            for r in rays.iter() {
+                frame[(y, *r)] = 2;
+            }
+
+            animation.push_frame(frame, |c| *c);
+        }
+    }
+
+    if let Some((animation, _)) = animation {
+        animation
+            .save("visualize/day07.gif")
+            .expect("Failed to save animation");
     }
 
     println!("Part 1: {}", part_1);
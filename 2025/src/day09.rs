@@ -1,20 +1,14 @@
-use geo::{Contains, Polygon};
+use common::parse::ints_n;
+use common::polygon::RectilinearPolygon;
+use std::cmp::Reverse;
 
-type Point = (f64, f64);
+type Point = (i64, i64);
 
 fn parse_input(input: &str) -> Vec<Point> {
     input
         .lines()
         .map(|line| {
-            let mut parts = line.split(',');
-            let x = parts
-                .next()
-                .and_then(|s| s.trim().parse::<_>().ok())
-                .unwrap();
-            let y = parts
-                .next()
-                .and_then(|s| s.trim().parse::<_>().ok())
-                .unwrap();
+            let [x, y] = ints_n(line).unwrap();
             (x, y)
         })
         .collect()
@@ -23,56 +17,31 @@ fn parse_input(input: &str) -> Vec<Point> {
 pub fn run(input: &str) {
     let red_points = parse_input(input);
 
-    let mut part_1 = 0.0;
-
     let mut areas_by_size: Vec<_> = red_points
         .iter()
         .enumerate()
         .flat_map(|(i, point)| {
             red_points
                 .iter()
+                .enumerate()
                 .skip(i + 1)
-                .map(|other_point| {
-                    let area = ((point.0 - other_point.0).abs() + 1.0)
-                        * ((point.1 - other_point.1).abs() + 1.0);
+                .map(|(j, other_point)| {
+                    let area = ((point.0 - other_point.0).abs() + 1)
+                        * ((point.1 - other_point.1).abs() + 1);
 
-                    (area, (point, other_point))
+                    (area, (i, j))
                 })
                 .collect::<Vec<_>>()
         })
         .collect();
-    areas_by_size.sort_by(|x, y| y.0.partial_cmp(&x.0).unwrap());
-
-    for (i, point) in red_points.iter().enumerate() {
-        for other_point in red_points.iter().skip(i + 1) {
-            let area =
-                ((point.0 - other_point.0).abs() + 1.0) * ((point.1 - other_point.1).abs() + 1.0);
-            if area > part_1 {
-                part_1 = area;
-            }
-        }
-    }
+    areas_by_size.sort_by_key(|x| Reverse(x.0));
 
     println!("Part 1: {}", areas_by_size[0].0);
 
-    let mut line_string_points = red_points.clone();
-    line_string_points.push(*red_points.first().unwrap());
-
-    let polygon: Polygon<_> = Polygon::new(geo::LineString::from(line_string_points), vec![]);
-
-    for (size, (point, other_point)) in areas_by_size {
-        let area_polygon = Polygon::new(
-            geo::LineString::from(vec![
-                *point,
-                (point.0, other_point.1),
-                *other_point,
-                (other_point.0, point.1),
-                *point,
-            ]),
-            vec![],
-        );
+    let polygon = RectilinearPolygon::new(&red_points);
 
-        if polygon.contains(&area_polygon) {
+    for (size, (i, j)) in areas_by_size {
+        if polygon.contains_vertex_rectangle(i, j) {
             println!("Part 2: {}", size);
             break;
         }
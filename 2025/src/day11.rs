@@ -1,61 +1,43 @@
-use cached::proc_macro::cached;
-use cached::SizedCache;
-use std::collections::HashMap;
+use common::graph::DirectedGraph;
+use common::parse;
 
-#[derive(Debug)]
-struct Machine<'a> {
-    transitions: HashMap<&'a str, Vec<&'a str>>,
-}
-
-fn parse_input<'a>(input: &'a str) -> Machine<'a> {
-    let mut transitions: HashMap<&str, Vec<&str>> = HashMap::new();
+fn parse_input(input: &str) -> DirectedGraph<&str> {
+    let mut transitions = DirectedGraph::new();
     for line in input.lines() {
-        let (from_raw, to_list) = line.split_once(":").unwrap();
-        let from = from_raw.trim();
+        let (from, to_list) = parse!(line, "{}: {}", &str, Vec<&str>).unwrap();
 
-        to_list.split_whitespace().for_each(|to| {
-            transitions.entry(from).or_default().push(to);
+        to_list.into_iter().for_each(|to| {
+            transitions.add_edge(from, to);
         });
     }
-    Machine { transitions }
+    transitions
 }
 
-#[cached(
-    ty = "SizedCache<String, usize>",
-    create = "{ SizedCache::with_size(1000000) }",
-    convert = r#"{ format!("{}{}", from, to) }"#
-)]
-fn find_path_count(machine: &Machine, from: &str, to: &str) -> usize {
-    if from == to {
-        1
-    } else {
-        machine
-            .transitions
-            .get(from)
-            .unwrap_or(&Vec::new())
-            .iter()
-            .map(|next| find_path_count(machine, next, to))
-            .sum()
-    }
+fn find_path_count(machine: &DirectedGraph<&str>, from: &str, to: &str, through: &[&str]) -> usize {
+    // A device missing from the list has no paths through it
+    let nodes: Option<Vec<usize>> = [from, to]
+        .iter()
+        .chain(through)
+        .map(|label| machine.index_of(label))
+        .collect();
+
+    let Some(nodes) = nodes else {
+        return 0;
+    };
+
+    machine
+        .count_paths_through(nodes[0], nodes[1], &nodes[2..])
+        .expect("Devices are connected in a cycle")
 }
 
 pub fn run(input: &str) {
     let machine = parse_input(input);
 
-    let part_1 = find_path_count(&machine, "you", "out");
+    let part_1 = find_path_count(&machine, "you", "out", &[]);
 
     println!("Part 1: {}", part_1);
 
-    let possible_paths = [["svr", "dac", "fft", "out"], ["svr", "fft", "dac", "out"]];
-
-    let part_2: usize = possible_paths
-        .iter()
-        .map(|path| {
-            path.windows(2)
-                .map(|window| find_path_count(&machine, window[0], window[1]))
-                .product::<usize>()
-        })
-        .sum();
+    let part_2 = find_path_count(&machine, "svr", "out", &["dac", "fft"]);
 
     println!("Part 2: {}", part_2);
 }
@@ -1,3 +1,6 @@
+use common::input::blocks;
+use common::parse;
+
 #[derive(Debug)]
 struct Shape {
     occupied: Vec<Vec<bool>>,
@@ -11,9 +14,11 @@ struct InputEntry {
 }
 
 fn parse_input(input: &str) -> (Vec<Shape>, Vec<InputEntry>) {
-    let sections: Vec<_> = input.split("\n\n").collect();
+    // Every shape is in its own block and all regions are in the last one
+    let sections = blocks(input);
+    let (regions_section, shape_sections) = sections.split_last().unwrap();
 
-    let shapes = sections[0..sections.len() - 1]
+    let shapes = shape_sections
         .iter()
         .map(|shape_section| Shape {
             occupied: shape_section
@@ -24,23 +29,12 @@ fn parse_input(input: &str) -> (Vec<Shape>, Vec<InputEntry>) {
         })
         .collect();
 
-    let entries = sections
-        .last()
-        .unwrap()
+    let entries = regions_section
         .lines()
         .map(|line| {
-            let (left, right) = line.split_once(": ").unwrap();
-            let (x, y) = left.split_once("x").unwrap();
-            let shape_counts = right
-                .split_whitespace()
-                .map(|s| s.parse().unwrap())
-                .collect();
+            let (x, y, shape_counts) = parse!(line, "{}x{}: {}", usize, usize, Vec<usize>).unwrap();
 
-            InputEntry {
-                x: x.parse().unwrap(),
-                y: y.parse().unwrap(),
-                shape_counts,
-            }
+            InputEntry { x, y, shape_counts }
         })
         .collect();
 
@@ -1,3 +1,5 @@
+use common::input::normalize;
+use common::visualize;
 use git2::Config as gitConfig;
 use reqwest::blocking::Client;
 use reqwest::header::{COOKIE, USER_AGENT};
@@ -44,7 +46,7 @@ fn input_file_path(day: i32) -> String {
 
 fn run_day(day: i32) {
     let path = input_file_path(day);
-    let input = fs::read_to_string(&path).unwrap();
+    let input = normalize(&fs::read_to_string(&path).unwrap());
 
     let start_time = Instant::now();
     day_func(day)(&input);
@@ -93,11 +95,17 @@ fn init_day(day: i32) {
 }
 
 fn write_usage() {
-    println!("Incorrect arguments, either day number to run code, or init followed by the day number to download input and create source file.");
+    println!("Incorrect arguments, either day number to run code, or init followed by the day number to download input and create source file. Add --visualize to save animations of the days that have them.");
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // Solutions that can be animated save their animations when asked to
+    if let Some(pos) = args.iter().position(|arg| arg == "--visualize") {
+        args.remove(pos);
+        visualize::enable();
+    }
 
     match args.len() {
         2 => {
@@ -0,0 +1,98 @@
+use crate::cycle::{self, Period};
+use crate::grid::{Connectivity, Grid};
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Cellular automaton given by a rule that computes the next value of a cell from the
+/// grid around it. A cell only depends on its neighbours by `connectivity`, which decides
+/// what the worklist mode has to look at again after a change.
+pub struct Automaton<F> {
+    connectivity: Connectivity,
+    rule: F,
+}
+
+impl<F> Automaton<F> {
+    pub fn new(connectivity: Connectivity, rule: F) -> Self {
+        Automaton { connectivity, rule }
+    }
+
+    /// Next generation with every cell updated at once from the current grid.
+    pub fn step<T>(&self, grid: &Grid<T>) -> Grid<T>
+    where
+        F: Fn(&Grid<T>, (usize, usize)) -> T,
+    {
+        Grid::from_fn(grid.width(), grid.height(), |pos| (self.rule)(grid, pos))
+    }
+
+    /// Steps until a generation equals the one before, returning it with the number of
+    /// steps that changed something.
+    pub fn run_until_stable<T: PartialEq>(&self, mut grid: Grid<T>) -> (Grid<T>, usize)
+    where
+        F: Fn(&Grid<T>, (usize, usize)) -> T,
+    {
+        let mut generations = 0;
+
+        loop {
+            let next = self.step(&grid);
+
+            if next == grid {
+                return (grid, generations);
+            }
+
+            grid = next;
+            generations += 1;
+        }
+    }
+
+    /// Period of the generations starting from `grid`, together with every generation
+    /// before the first repeated one.
+    pub fn period<T: Clone + Eq + Hash>(&self, grid: &Grid<T>) -> (Period, Vec<Grid<T>>)
+    where
+        F: Fn(&Grid<T>, (usize, usize)) -> T,
+    {
+        cycle::find_period(grid, |grid| Some(self.step(grid)))
+            .expect("Generations of a grid never end")
+    }
+
+    /// Generation after `steps` steps, skipping whole cycles once one is found.
+    pub fn state_after<T: Clone + Eq + Hash>(&self, grid: Grid<T>, steps: usize) -> Grid<T>
+    where
+        F: Fn(&Grid<T>, (usize, usize)) -> T,
+    {
+        cycle::state_after(grid, |grid| Some(self.step(grid)), steps)
+            .expect("Generations of a grid never end")
+    }
+
+    /// Updates cells one at a time in place, each seeing the changes made before it, until
+    /// no cell changes. Only the neighbours of a changed cell are looked at again. Returns
+    /// the number of changes.
+    pub fn settle<T: PartialEq>(&self, grid: &mut Grid<T>) -> usize
+    where
+        F: Fn(&Grid<T>, (usize, usize)) -> T,
+    {
+        let mut queued = grid.map(|_| true);
+        let mut worklist: VecDeque<(usize, usize)> = grid.positions().collect();
+        let mut changes = 0;
+
+        while let Some(pos) = worklist.pop_front() {
+            queued[pos] = false;
+            let next = (self.rule)(grid, pos);
+
+            if next == grid[pos] {
+                continue;
+            }
+
+            grid[pos] = next;
+            changes += 1;
+
+            for neighbour in grid.neighbours(pos, self.connectivity) {
+                if !queued[neighbour] {
+                    queued[neighbour] = true;
+                    worklist.push_back(neighbour);
+                }
+            }
+        }
+
+        changes
+    }
+}
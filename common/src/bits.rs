@@ -0,0 +1,315 @@
+use std::ops::{BitAnd, BitOr, BitXor, Shl, Shr};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Fixed number of bits packed into words. Bits past the length are always zero, so equal
+/// sets compare and hash equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// `len` bits, all of them off.
+    pub fn new(len: usize) -> Self {
+        BitSet {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// `len` bits with the ones at `indices` on.
+    pub fn from_indices(len: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut set = BitSet::new(len);
+
+        for index in indices {
+            set.insert(index);
+        }
+
+        set
+    }
+
+    /// Number of bits, not only the ones that are on.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.check(index);
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        if value {
+            self.insert(index);
+        } else {
+            self.remove(index);
+        }
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        self.check(index);
+        self.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        self.check(index);
+        self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        self.check(index);
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+    }
+
+    /// Number of bits that are on.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|w| *w != 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Indices of the bits that are on, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, word)| {
+            let mut rest = *word;
+
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+
+                Some(i * WORD_BITS + bit)
+            })
+        })
+    }
+
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "Bit {} is out of a set of {} bits",
+            index,
+            self.len
+        );
+    }
+
+    fn check_same_len(&self, other: &BitSet) {
+        assert_eq!(
+            self.len, other.len,
+            "Bit sets of different lengths can't be combined"
+        );
+    }
+
+    // Turns off the bits past the length that a shift may have moved there
+    fn trim(&mut self) {
+        let used = self.len % WORD_BITS;
+
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+
+    fn combine(&self, other: &BitSet, op: impl Fn(u64, u64) -> u64) -> BitSet {
+        self.check_same_len(other);
+
+        BitSet {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| op(*a, *b))
+                .collect(),
+            len: self.len,
+        }
+    }
+}
+
+impl FromIterator<bool> for BitSet {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let bits: Vec<bool> = iter.into_iter().collect();
+
+        BitSet::from_indices(bits.len(), (0..bits.len()).filter(|i| bits[*i]))
+    }
+}
+
+impl BitAnd for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, other: &BitSet) -> BitSet {
+        self.combine(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, other: &BitSet) -> BitSet {
+        self.combine(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: &BitSet) -> BitSet {
+        self.combine(other, |a, b| a ^ b)
+    }
+}
+
+/// Moves every bit `n` indices up, dropping the ones moved past the length.
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shl(self, n: usize) -> BitSet {
+        let (word_shift, bit_shift) = (n / WORD_BITS, n % WORD_BITS);
+        let mut words = vec![0; self.words.len()];
+
+        for (i, word) in words.iter_mut().enumerate().skip(word_shift) {
+            let from = i - word_shift;
+            *word = self.words[from] << bit_shift;
+
+            if bit_shift > 0 && from > 0 {
+                *word |= self.words[from - 1] >> (WORD_BITS - bit_shift);
+            }
+        }
+
+        let mut result = BitSet {
+            words,
+            len: self.len,
+        };
+
+        result.trim();
+        result
+    }
+}
+
+/// Moves every bit `n` indices down, dropping the ones moved below zero.
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shr(self, n: usize) -> BitSet {
+        let (word_shift, bit_shift) = (n / WORD_BITS, n % WORD_BITS);
+        let mut words = vec![0; self.words.len()];
+
+        for (i, word) in words
+            .iter_mut()
+            .enumerate()
+            .take(self.words.len().saturating_sub(word_shift))
+        {
+            let from = i + word_shift;
+            *word = self.words[from] >> bit_shift;
+
+            if bit_shift > 0 && from + 1 < self.words.len() {
+                *word |= self.words[from + 1] << (WORD_BITS - bit_shift);
+            }
+        }
+
+        BitSet {
+            words,
+            len: self.len,
+        }
+    }
+}
+
+/// Rectangle of bits stored as one [`BitSet`] per row, with columns as bit indices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    rows: Vec<BitSet>,
+    width: usize,
+}
+
+impl BitGrid {
+    /// `height` rows of `width` bits, all of them off.
+    pub fn new(width: usize, height: usize) -> Self {
+        BitGrid {
+            rows: vec![BitSet::new(width); height],
+            width,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> bool {
+        self.rows[row].contains(column)
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, value: bool) {
+        self.rows[row].set(column, value);
+    }
+
+    pub fn row(&self, row: usize) -> &BitSet {
+        &self.rows[row]
+    }
+
+    pub fn rows(&self) -> &[BitSet] {
+        &self.rows
+    }
+
+    /// Number of bits that are on.
+    pub fn count_ones(&self) -> usize {
+        self.rows.iter().map(BitSet::count_ones).sum()
+    }
+
+    pub fn clear(&mut self) {
+        for row in self.rows.iter_mut() {
+            row.clear();
+        }
+    }
+
+    /// Row and column of every bit that is on, row by row.
+    pub fn ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(r, row)| row.ones().map(move |c| (r, c)))
+    }
+
+    /// The grid drawn with one character per bit and a line per row.
+    pub fn render(&self, on: char, off: char) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height());
+
+        for row in &self.rows {
+            result.extend((0..self.width).map(|c| if row.contains(c) { on } else { off }));
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+/// Grid of the given rows, which have to be equally long.
+impl FromIterator<BitSet> for BitGrid {
+    fn from_iter<I: IntoIterator<Item = BitSet>>(iter: I) -> Self {
+        let rows: Vec<BitSet> = iter.into_iter().collect();
+        let width = rows.first().map_or(0, BitSet::len);
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Rows of a bit grid have to be equally long"
+        );
+
+        BitGrid { rows, width }
+    }
+}
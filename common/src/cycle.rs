@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cycle of a sequence `x0, f(x0), f(f(x0)), ...` given by a step function `f`, which
+/// returns `None` when the sequence ends without one. Step `start` is the first one with a
+/// state that repeats, and it repeats every `length` steps from there on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Period {
+    /// The first step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, keeping only two states around.
+pub fn floyd<S: Clone + PartialEq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Period> {
+    let mut tortoise = step(start)?;
+    let mut hare = step(&tortoise)?;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        let halfway = step(&hare)?;
+        hare = step(&halfway)?;
+    }
+
+    // The hare is now a multiple of the cycle length ahead, so moving both at the same
+    // speed from the start and the meeting point makes them meet at the cycle start
+    let mut tortoise = start.clone();
+    let mut cycle_start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        cycle_start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise)?;
+
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    Some(Period {
+        start: cycle_start,
+        length,
+    })
+}
+
+/// Brent's algorithm, keeping only two states around and calling `step` less often than
+/// [`floyd`].
+pub fn brent<S: Clone + PartialEq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Period> {
+    // The tortoise jumps to the hare every power of two steps until the hare catches it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start)?;
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+
+    let mut cycle_start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        cycle_start += 1;
+    }
+
+    Some(Period {
+        start: cycle_start,
+        length,
+    })
+}
+
+/// Remembers every state, which calls `step` the fewest times. Returns the period together
+/// with the states of all steps before the first repetition.
+pub fn find_period<S: Clone + Eq + Hash>(
+    start: &S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<(Period, Vec<S>)> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut cur = start.clone();
+
+    loop {
+        if let Some(cycle_start) = seen.get(&cur) {
+            let period = Period {
+                start: *cycle_start,
+                length: states.len() - cycle_start,
+            };
+
+            return Some((period, states));
+        }
+
+        seen.insert(cur.clone(), states.len());
+        let next = step(&cur)?;
+        states.push(cur);
+        cur = next;
+    }
+}
+
+/// State after `steps` steps, skipping whole cycles once one is found. Returns `None` if
+/// the sequence ends before.
+pub fn state_after<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+    steps: usize,
+) -> Option<S> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut cur = start;
+
+    while states.len() < steps {
+        if let Some(cycle_start) = seen.get(&cur) {
+            let period = Period {
+                start: *cycle_start,
+                length: states.len() - cycle_start,
+            };
+
+            return Some(states.swap_remove(period.reduce(steps)));
+        }
+
+        seen.insert(cur.clone(), states.len());
+        let next = step(&cur)?;
+        states.push(cur);
+        cur = next;
+    }
+
+    Some(cur)
+}
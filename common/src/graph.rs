@@ -0,0 +1,425 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Undirected graph over labelled nodes. Nodes get indices in the order they are first
+/// added, so every result below is reported in a stable order independent of hashing.
+#[derive(Debug, Clone)]
+pub struct UndirectedGraph<N> {
+    labels: Vec<N>,
+    indices: HashMap<N, usize>,
+    // Sorted and without duplicates
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl<N: Eq + Hash + Clone> Default for UndirectedGraph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Eq + Hash + Clone> UndirectedGraph<N> {
+    pub fn new() -> Self {
+        UndirectedGraph {
+            labels: Vec::new(),
+            indices: HashMap::new(),
+            neighbours: Vec::new(),
+        }
+    }
+
+    /// Index of the node, adding it first if needed.
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(index) = self.indices.get(&label) {
+            return *index;
+        }
+
+        self.labels.push(label.clone());
+        self.indices.insert(label, self.labels.len() - 1);
+        self.neighbours.push(Vec::new());
+
+        self.labels.len() - 1
+    }
+
+    pub fn add_edge(&mut self, a: N, b: N) {
+        let a = self.add_node(a);
+        let b = self.add_node(b);
+
+        for (from, to) in [(a, b), (b, a)] {
+            if let Err(pos) = self.neighbours[from].binary_search(&to) {
+                self.neighbours[from].insert(pos, to);
+            }
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn index_of(&self, label: &N) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+
+    pub fn label(&self, index: usize) -> &N {
+        &self.labels[index]
+    }
+
+    /// Neighbours of the node in increasing index order.
+    pub fn neighbours(&self, index: usize) -> &[usize] {
+        &self.neighbours[index]
+    }
+
+    pub fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        self.neighbours[a].binary_search(&b).is_ok()
+    }
+
+    /// All cliques of exactly `k` nodes, each sorted, in lexicographic order.
+    pub fn cliques_of_size(&self, k: usize) -> Vec<Vec<usize>> {
+        let mut result = Vec::new();
+        let mut cur = Vec::with_capacity(k);
+        let all: Vec<usize> = (0..self.node_count()).collect();
+
+        self.extend_clique(k, &all, &mut cur, &mut result);
+
+        result
+    }
+
+    pub fn triangles(&self) -> Vec<Vec<usize>> {
+        self.cliques_of_size(3)
+    }
+
+    // Candidates are the nodes after the last one in `cur` connected to all of it
+    fn extend_clique(
+        &self,
+        k: usize,
+        candidates: &[usize],
+        cur: &mut Vec<usize>,
+        result: &mut Vec<Vec<usize>>,
+    ) {
+        if cur.len() == k {
+            result.push(cur.clone());
+            return;
+        }
+
+        for (i, node) in candidates.iter().enumerate() {
+            if candidates.len() - i < k - cur.len() {
+                break;
+            }
+
+            let next = intersect(&candidates[i + 1..], &self.neighbours[*node]);
+
+            cur.push(*node);
+            self.extend_clique(k, &next, cur, result);
+            cur.pop();
+        }
+    }
+
+    /// All cliques that can't be extended by another node (Bron–Kerbosch with pivoting),
+    /// each sorted, in lexicographic order.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut result = Vec::new();
+        let candidates: Vec<usize> = (0..self.node_count()).collect();
+
+        self.bron_kerbosch(&mut Vec::new(), candidates, Vec::new(), &mut result);
+
+        for clique in result.iter_mut() {
+            clique.sort_unstable();
+        }
+
+        result.sort_unstable();
+        result
+    }
+
+    /// All cliques of the largest size. There is more than one only if the maximum is tied.
+    pub fn maximum_cliques(&self) -> Vec<Vec<usize>> {
+        let cliques = self.maximal_cliques();
+        let max_size = cliques.iter().map(|c| c.len()).max().unwrap_or(0);
+
+        cliques
+            .into_iter()
+            .filter(|c| c.len() == max_size)
+            .collect()
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<usize>,
+        mut excluded: Vec<usize>,
+        result: &mut Vec<Vec<usize>>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                result.push(clique.clone());
+            }
+
+            return;
+        }
+
+        // Any maximal clique contains the pivot or one of its non-neighbours, so only
+        // those have to be tried
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .max_by_key(|node| intersect(&candidates, &self.neighbours[**node]).len())
+            .copied()
+            .unwrap();
+
+        let to_try: Vec<usize> = candidates
+            .iter()
+            .filter(|node| !self.is_adjacent(pivot, **node))
+            .copied()
+            .collect();
+
+        for node in to_try {
+            let neighbours = &self.neighbours[node];
+
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                intersect(&candidates, neighbours),
+                intersect(&excluded, neighbours),
+                result,
+            );
+            clique.pop();
+
+            candidates.retain(|c| *c != node);
+
+            let pos = excluded.binary_search(&node).unwrap_err();
+            excluded.insert(pos, node);
+        }
+    }
+}
+
+/// Nodes forming a cycle, in order, found in a graph that has to be acyclic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<usize>);
+
+/// Directed graph over labelled nodes, indexed like [`UndirectedGraph`].
+#[derive(Debug, Clone)]
+pub struct DirectedGraph<N> {
+    labels: Vec<N>,
+    indices: HashMap<N, usize>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl<N: Eq + Hash + Clone> Default for DirectedGraph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Eq + Hash + Clone> DirectedGraph<N> {
+    pub fn new() -> Self {
+        DirectedGraph {
+            labels: Vec::new(),
+            indices: HashMap::new(),
+            successors: Vec::new(),
+        }
+    }
+
+    /// Index of the node, adding it first if needed.
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(index) = self.indices.get(&label) {
+            return *index;
+        }
+
+        self.labels.push(label.clone());
+        self.indices.insert(label, self.labels.len() - 1);
+        self.successors.push(Vec::new());
+
+        self.labels.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        if !self.successors[from].contains(&to) {
+            self.successors[from].push(to);
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn index_of(&self, label: &N) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+
+    pub fn label(&self, index: usize) -> &N {
+        &self.labels[index]
+    }
+
+    pub fn successors(&self, index: usize) -> &[usize] {
+        &self.successors[index]
+    }
+
+    /// Every node after all of its predecessors, picking the lowest index whenever there
+    /// is a choice.
+    pub fn topological_order(&self) -> Result<Vec<usize>, Cycle> {
+        self.order_of(&vec![true; self.node_count()])
+    }
+
+    // Every node reachable from `from`, including itself, after all of its predecessors.
+    // Cycles elsewhere in the graph don't matter.
+    fn topological_order_from(&self, from: usize) -> Result<Vec<usize>, Cycle> {
+        let mut reachable = vec![false; self.node_count()];
+        let mut stack = vec![from];
+        reachable[from] = true;
+
+        while let Some(node) = stack.pop() {
+            for next in &self.successors[node] {
+                if !reachable[*next] {
+                    reachable[*next] = true;
+                    stack.push(*next);
+                }
+            }
+        }
+
+        self.order_of(&reachable)
+    }
+
+    // Kahn's algorithm over the included nodes, which have to have all their successors
+    // included too
+    fn order_of(&self, included: &[bool]) -> Result<Vec<usize>, Cycle> {
+        let mut in_degree = vec![0; self.node_count()];
+
+        for (node, successors) in self.successors.iter().enumerate() {
+            if included[node] {
+                for next in successors {
+                    in_degree[*next] += 1;
+                }
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.node_count())
+            .filter(|node| included[*node] && in_degree[*node] == 0)
+            .map(Reverse)
+            .collect();
+
+        let count = included.iter().filter(|i| **i).count();
+        let mut order = Vec::with_capacity(count);
+
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+
+            for next in &self.successors[node] {
+                in_degree[*next] -= 1;
+
+                if in_degree[*next] == 0 {
+                    ready.push(Reverse(*next));
+                }
+            }
+        }
+
+        if order.len() == count {
+            Ok(order)
+        } else {
+            Err(self.find_cycle(&in_degree))
+        }
+    }
+
+    // Nodes left with incoming edges after Kahn's algorithm all have a predecessor among
+    // themselves, so walking backwards through them has to close a cycle
+    fn find_cycle(&self, in_degree: &[usize]) -> Cycle {
+        let mut predecessor = vec![None; self.node_count()];
+
+        for (node, successors) in self.successors.iter().enumerate() {
+            if in_degree[node] > 0 {
+                for next in successors {
+                    if in_degree[*next] > 0 {
+                        predecessor[*next] = Some(node);
+                    }
+                }
+            }
+        }
+
+        let mut seen = vec![false; self.node_count()];
+        let mut cur = (0..self.node_count()).find(|n| in_degree[*n] > 0).unwrap();
+
+        while !seen[cur] {
+            seen[cur] = true;
+            cur = predecessor[cur].unwrap();
+        }
+
+        let mut cycle = vec![cur];
+        let mut node = predecessor[cur].unwrap();
+
+        while node != cur {
+            cycle.push(node);
+            node = predecessor[node].unwrap();
+        }
+
+        cycle.reverse();
+        Cycle(cycle)
+    }
+
+    /// Number of distinct paths from `from` to `to`.
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<usize, Cycle> {
+        self.count_paths_through(from, to, &[])
+    }
+
+    /// Number of distinct paths from `from` to `to` visiting every node of `required`, in
+    /// any order. Nodes listed more than once in `required` count once. Fails only on a
+    /// cycle reachable from `from`.
+    pub fn count_paths_through(
+        &self,
+        from: usize,
+        to: usize,
+        required: &[usize],
+    ) -> Result<usize, Cycle> {
+        let order = self.topological_order_from(from)?;
+
+        let mut required = required.to_vec();
+        required.sort_unstable();
+        required.dedup();
+
+        let bit = |node: usize| {
+            required
+                .iter()
+                .position(|r| *r == node)
+                .map_or(0, |pos| 1 << pos)
+        };
+
+        // Paths from `from` to every node, split by which required nodes they visited
+        let mut ways = vec![vec![0; 1 << required.len()]; self.node_count()];
+        ways[from][bit(from)] = 1;
+
+        for node in order {
+            for mask in 0..ways[node].len() {
+                let count = ways[node][mask];
+
+                if count == 0 {
+                    continue;
+                }
+
+                for next in &self.successors[node] {
+                    ways[*next][mask | bit(*next)] += count;
+                }
+            }
+        }
+
+        Ok(ways[to][(1 << required.len()) - 1])
+    }
+}
+
+// Both slices have to be sorted
+fn intersect(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                result.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    result
+}
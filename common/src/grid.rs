@@ -0,0 +1,284 @@
+use std::ops::{Index, IndexMut};
+
+pub(crate) const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+pub(crate) const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+const ALL: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/// Which cells count as next to each other: the four sharing an edge, or also the four
+/// sharing only a corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub(crate) fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &ORTHOGONAL,
+            Connectivity::Eight => &ALL,
+        }
+    }
+
+    // Positions next to `pos` for which `contains` holds, which keeps the bounds check with
+    // whatever stores the cells
+    pub(crate) fn neighbours(
+        self,
+        (row, column): (usize, usize),
+        contains: impl Fn((usize, usize)) -> bool,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets().iter().filter_map(move |(dr, dc)| {
+            let pos = (
+                row.checked_add_signed(*dr)?,
+                column.checked_add_signed(*dc)?,
+            );
+
+            contains(pos).then_some(pos)
+        })
+    }
+
+    // Cells outside of a region have to use the other connectivity, otherwise they could
+    // leak through its diagonal gaps or get cut off by them
+    pub(crate) fn complement(self) -> Connectivity {
+        match self {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        }
+    }
+}
+
+/// One of the four orthogonal directions on a grid, with up towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Change of `(row, column)` for one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// Rectangle of cells stored row by row, with positions given as `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// `height` rows of `width` copies of `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Grid with every cell computed from its position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(&mut cell)
+            .collect();
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Grid with a cell for every character of the lines of `input`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        input
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): (usize, usize)) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// First position, row by row, of a cell matching `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Positions next to `pos` that are inside the grid.
+    pub fn neighbours(
+        &self,
+        (row, column): (usize, usize),
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        connectivity.neighbours((row, column), move |(r, c)| r < height && c < width)
+    }
+
+    /// Position one step from `pos` in `direction`, if it is inside the grid.
+    pub fn step(
+        &self,
+        (row, column): (usize, usize),
+        direction: Direction,
+    ) -> Option<(usize, usize)> {
+        let (dr, dc) = direction.offset();
+        let r = row.checked_add_signed(dr)?;
+        let c = column.checked_add_signed(dc)?;
+
+        (r < self.height && c < self.width).then_some((r, c))
+    }
+
+    /// Number of cells next to `pos` matching `predicate`.
+    pub fn count_neighbours(
+        &self,
+        pos: (usize, usize),
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> usize {
+        self.neighbours(pos, connectivity)
+            .filter(|n| predicate(&self[*n]))
+            .count()
+    }
+
+    /// Grid of the same size with every cell mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid drawn with one character per cell and a line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            result.extend(row.iter().map(&cell));
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(
+            row < self.height && column < self.width,
+            "Position ({}, {}) is out of a {}x{} grid",
+            row,
+            column,
+            self.width,
+            self.height
+        );
+
+        &self.cells[row * self.width + column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.height && column < self.width,
+            "Position ({}, {}) is out of a {}x{} grid",
+            row,
+            column,
+            self.width,
+            self.height
+        );
+
+        &mut self.cells[row * self.width + column]
+    }
+}
+
+/// Grid of the given rows, which have to be equally long.
+impl<T> FromIterator<Vec<T>> for Grid<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for row in iter {
+            assert_eq!(
+                *width.get_or_insert(row.len()),
+                row.len(),
+                "Rows of a grid have to be equally long"
+            );
+
+            cells.extend(row);
+            height += 1;
+        }
+
+        Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        }
+    }
+}
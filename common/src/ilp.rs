@@ -0,0 +1,254 @@
+use crate::linear::{solve, SolutionSpace};
+use crate::math::lcm;
+use std::cmp::Ordering;
+
+/// Minimizes `cost · x` subject to `coefficients · x = constants` and
+/// `0 <= x[i] <= upper_bounds[i]` over integers.
+///
+/// The system is reduced with exact Gaussian elimination first, so only the free
+/// variables are searched. Each of them only takes values that keep every variable
+/// within its bounds, cheaper values first, and a branch is left once it can't beat the
+/// best solution found. That keeps it fast for the small, nearly determined systems the
+/// puzzles produce. Returns the minimal cost together with the solution, or `None`
+/// if there is no solution within the bounds.
+pub fn minimize(
+    coefficients: &[Vec<i128>],
+    constants: &[i128],
+    cost: &[i128],
+    upper_bounds: &[i128],
+) -> Option<(i128, Vec<i128>)> {
+    minimize_in(&solve(coefficients, constants)?, cost, upper_bounds)
+}
+
+/// Minimizes `cost · x` over the integer points of an already solved system with
+/// `0 <= x[i] <= upper_bounds[i]`, see [`minimize`].
+pub fn minimize_in(
+    space: &SolutionSpace,
+    cost: &[i128],
+    upper_bounds: &[i128],
+) -> Option<(i128, Vec<i128>)> {
+    // Every variable is scaled to integers: x[i] * scale[i] = base[i] + sum(t[k] * step[k][i])
+    let scales: Vec<i128> = (0..space.particular.len())
+        .map(|i| {
+            space
+                .directions
+                .iter()
+                .map(|d| d[i].denominator())
+                .fold(space.particular[i].denominator(), lcm)
+        })
+        .collect();
+
+    let base: Vec<i128> = space
+        .particular
+        .iter()
+        .zip(&scales)
+        .map(|(p, s)| p.numerator() * (s / p.denominator()))
+        .collect();
+
+    let steps: Vec<Vec<i128>> = space
+        .directions
+        .iter()
+        .map(|d| {
+            d.iter()
+                .zip(&scales)
+                .map(|(x, s)| x.numerator() * (s / x.denominator()))
+                .collect()
+        })
+        .collect();
+
+    let free_bounds: Vec<i128> = space
+        .free_variables
+        .iter()
+        .map(|v| upper_bounds[*v])
+        .collect();
+
+    // Costs are compared scaled by the lcm of all scales, which keeps them integers:
+    // cost · x * common_scale = sum(cost_weights[i] * scaled[i])
+    let common_scale = scales.iter().copied().fold(1, lcm);
+    let cost_weights: Vec<i128> = cost
+        .iter()
+        .zip(&scales)
+        .map(|(c, s)| c * (common_scale / s))
+        .collect();
+    let free_costs: Vec<i128> = steps
+        .iter()
+        .map(|step| step.iter().zip(&cost_weights).map(|(x, w)| x * w).sum())
+        .collect();
+
+    // What the free variables from index k on can still add to every scaled variable and
+    // to the scaled cost, at least and at most
+    let mut rest_min: Vec<Vec<i128>> = vec![vec![0; scales.len()]; steps.len() + 1];
+    let mut rest_max: Vec<Vec<i128>> = vec![vec![0; scales.len()]; steps.len() + 1];
+    let mut rest_cost = vec![0; steps.len() + 1];
+
+    for k in (0..steps.len()).rev() {
+        for i in 0..scales.len() {
+            let change = steps[k][i].saturating_mul(free_bounds[k]);
+            rest_min[k][i] = rest_min[k + 1][i].saturating_add(change.min(0));
+            rest_max[k][i] = rest_max[k + 1][i].saturating_add(change.max(0));
+        }
+
+        rest_cost[k] = rest_cost[k + 1] + (free_costs[k] * free_bounds[k]).min(0);
+    }
+
+    let scaled_bounds: Vec<i128> = upper_bounds
+        .iter()
+        .zip(&scales)
+        .map(|(b, s)| b.saturating_mul(*s))
+        .collect();
+
+    let search = Search {
+        scales: &scales,
+        steps: &steps,
+        cost,
+        upper_bounds,
+        free_bounds: &free_bounds,
+        scaled_bounds: &scaled_bounds,
+        cost_weights: &cost_weights,
+        free_costs: &free_costs,
+        rest_min: &rest_min,
+        rest_max: &rest_max,
+        rest_cost: &rest_cost,
+    };
+
+    let mut scaled = base;
+    let mut best = None;
+    search.run(0, &mut scaled, &mut best);
+
+    best.map(|(_, total, x)| (total, x))
+}
+
+// Best solution so far as its scaled cost, its cost and the solution
+type Best = Option<(i128, i128, Vec<i128>)>;
+
+struct Search<'a> {
+    scales: &'a [i128],
+    steps: &'a [Vec<i128>],
+    cost: &'a [i128],
+    upper_bounds: &'a [i128],
+    free_bounds: &'a [i128],
+    scaled_bounds: &'a [i128],
+    cost_weights: &'a [i128],
+    free_costs: &'a [i128],
+    rest_min: &'a [Vec<i128>],
+    rest_max: &'a [Vec<i128>],
+    rest_cost: &'a [i128],
+}
+
+impl Search<'_> {
+    // Tries every value of the free variable `free_ix` that can still lead to a solution
+    // within the bounds, changing `scaled` in place and restoring it before returning
+    fn run(&self, free_ix: usize, scaled: &mut [i128], best: &mut Best) {
+        if free_ix == self.free_bounds.len() {
+            self.evaluate(scaled, best);
+            return;
+        }
+
+        let Some((from, to)) = self.value_range(free_ix, scaled) else {
+            return;
+        };
+
+        let step = &self.steps[free_ix];
+        let free_cost = self.free_costs[free_ix];
+        let scaled_cost: i128 = scaled
+            .iter()
+            .zip(self.cost_weights)
+            .map(|(x, w)| x * w)
+            .sum();
+
+        // Cheaper values first, so the bound below cuts off as much as possible
+        let (mut t, delta) = if free_cost >= 0 { (from, 1) } else { (to, -1) };
+        let mut applied = 0;
+
+        while from <= t && t <= to {
+            // Values further on only cost more
+            let lowest = scaled_cost + free_cost * t + self.rest_cost[free_ix + 1];
+
+            if best
+                .as_ref()
+                .is_some_and(|(best_cost, _, _)| lowest >= *best_cost)
+            {
+                break;
+            }
+
+            for (x, s) in scaled.iter_mut().zip(step) {
+                *x += s * (t - applied);
+            }
+
+            applied = t;
+            self.run(free_ix + 1, scaled, best);
+            t += delta;
+        }
+
+        for (x, s) in scaled.iter_mut().zip(step) {
+            *x -= s * applied;
+        }
+    }
+
+    // Values of the free variable for which every variable can still end up within its
+    // bounds, given what the later free variables can add
+    fn value_range(&self, free_ix: usize, scaled: &[i128]) -> Option<(i128, i128)> {
+        let mut from = 0;
+        let mut to = self.free_bounds[free_ix];
+
+        for (i, s) in self.steps[free_ix].iter().enumerate() {
+            // Needs low <= s * t <= high
+            let low = (-scaled[i]).saturating_sub(self.rest_max[free_ix + 1][i]);
+            let high = self.scaled_bounds[i]
+                .saturating_sub(scaled[i])
+                .saturating_sub(self.rest_min[free_ix + 1][i]);
+
+            match s.cmp(&0) {
+                Ordering::Greater => {
+                    from = from.max(ceil_div(low, *s));
+                    to = to.min(high.div_euclid(*s));
+                }
+                Ordering::Less => {
+                    from = from.max(ceil_div(-high, -s));
+                    to = to.min((-low).div_euclid(-s));
+                }
+                Ordering::Equal if low > 0 || high < 0 => return None,
+                Ordering::Equal => {}
+            }
+        }
+
+        (from <= to).then_some((from, to))
+    }
+
+    fn evaluate(&self, scaled: &[i128], best: &mut Best) {
+        let mut x = Vec::with_capacity(scaled.len());
+
+        for ((value, scale), bound) in scaled.iter().zip(self.scales).zip(self.upper_bounds) {
+            if value % scale != 0 {
+                return;
+            }
+
+            let value = value / scale;
+
+            if value < 0 || value > *bound {
+                return;
+            }
+
+            x.push(value);
+        }
+
+        let scaled_cost: i128 = scaled
+            .iter()
+            .zip(self.cost_weights)
+            .map(|(x, w)| x * w)
+            .sum();
+
+        if best
+            .as_ref()
+            .is_none_or(|(best_cost, _, _)| scaled_cost < *best_cost)
+        {
+            let total = x.iter().zip(self.cost).map(|(x, c)| x * c).sum();
+            *best = Some((scaled_cost, total, x));
+        }
+    }
+}
+
+// Smallest integer at least a / b, for a positive b
+fn ceil_div(a: i128, b: i128) -> i128 {
+    -(-a).div_euclid(b)
+}
@@ -0,0 +1,47 @@
+/// Input with Windows line endings turned into plain line feeds.
+pub fn normalize(input: &str) -> String {
+    input.replace("\r\n", "\n")
+}
+
+/// Parts of the input separated by blank lines, without the line breaks around them.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some(block_start) = start.take() {
+                result.push(&input[block_start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(block_start) = start {
+        result.push(&input[block_start..end]);
+    }
+
+    result
+}
+
+/// The `N` parts of the input separated by blank lines, e.g. a header section and a body
+/// section as `let [rules, updates] = sections(input);`.
+pub fn sections<const N: usize>(input: &str) -> [&str; N] {
+    let found = blocks(input);
+
+    found.try_into().unwrap_or_else(|found: Vec<&str>| {
+        panic!(
+            "Expected {} sections separated by blank lines but found {}",
+            N,
+            found.len()
+        )
+    })
+}
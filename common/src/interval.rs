@@ -0,0 +1,335 @@
+use crate::math::Integer;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// Set of integers stored as disjoint, non-adjacent half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T: Integer + Ord> {
+    // Start of every range mapped to its exclusive end
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T: Integer + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the range, merging it with every range it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        let mut start = range.start;
+        let mut end = range.end;
+
+        // The range starting before `start` may reach into the inserted one
+        if let Some((prev_start, prev_end)) = self.ranges.range(..start).next_back() {
+            if *prev_end >= start {
+                start = *prev_start;
+                end = end.max(*prev_end);
+            }
+        }
+
+        let overlapping: Vec<(T, T)> = self
+            .ranges
+            .range(start..=end)
+            .map(|(s, e)| (*s, *e))
+            .collect();
+
+        for (s, e) in overlapping {
+            end = end.max(e);
+            self.ranges.remove(&s);
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+    /// Removes every value of the range from the set.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        // Ranges starting before the removed one may need to be cut
+        if let Some((prev_start, prev_end)) = self.ranges.range(..range.start).next_back() {
+            let (prev_start, prev_end) = (*prev_start, *prev_end);
+
+            if prev_end > range.start {
+                self.ranges.insert(prev_start, range.start);
+
+                if prev_end > range.end {
+                    self.ranges.insert(range.end, prev_end);
+                }
+            }
+        }
+
+        let overlapping: Vec<(T, T)> = self
+            .ranges
+            .range(range.start..range.end)
+            .map(|(s, e)| (*s, *e))
+            .collect();
+
+        for (s, e) in overlapping {
+            self.ranges.remove(&s);
+
+            if e > range.end {
+                self.ranges.insert(range.end, e);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, end)| *end > value)
+    }
+
+    /// The range containing the value, if any.
+    pub fn range_of(&self, value: T) -> Option<Range<T>> {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .filter(|(_, end)| **end > value)
+            .map(|(start, end)| *start..*end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of disjoint ranges.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Total number of values covered by the set.
+    pub fn covered_length(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |agg, (start, end)| agg + (*end - *start))
+    }
+
+    /// Disjoint ranges in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().map(|(start, end)| *start..*end)
+    }
+
+    /// Ranges between the covered ones, in increasing order.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges
+            .values()
+            .zip(self.ranges.keys().skip(1))
+            .map(|(end, next_start)| *end..*next_start)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+
+        for range in other.iter() {
+            result.insert(range);
+        }
+
+        result
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+
+        for range in other.iter() {
+            result.remove(range);
+        }
+
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = IntervalSet::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = x.start.max(y.start);
+            let end = x.end.min(y.end);
+
+            if start < end {
+                result.ranges.insert(start, end);
+            }
+
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Integer + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut result = IntervalSet::new();
+
+        for range in iter {
+            result.insert(range);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Values the tests use, small enough for ranges to touch and overlap often
+    const SIZE: i64 = 40;
+
+    #[derive(Debug, Clone)]
+    enum Operation {
+        Insert(Range<i64>),
+        Remove(Range<i64>),
+    }
+
+    // Also gives empty and reversed ranges, which have to be ignored
+    fn range() -> impl Strategy<Value = Range<i64>> {
+        (0..=SIZE, 0..=SIZE).prop_map(|(start, end)| start..end)
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            range().prop_map(Operation::Insert),
+            range().prop_map(Operation::Remove),
+        ]
+    }
+
+    fn apply(model: &mut [bool], range: &Range<i64>, value: bool) {
+        for x in range.clone() {
+            model[x as usize] = value;
+        }
+    }
+
+    // Maximal runs of covered values in the model
+    fn runs(model: &[bool]) -> Vec<Range<i64>> {
+        let mut result = Vec::new();
+        let mut start = None;
+
+        for (x, covered) in model.iter().chain([&false]).enumerate() {
+            match (start, covered) {
+                (None, true) => start = Some(x as i64),
+                (Some(s), false) => {
+                    result.push(s..x as i64);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        result
+    }
+
+    fn check(set: &IntervalSet<i64>, model: &[bool]) -> Result<(), TestCaseError> {
+        for x in -2..SIZE + 2 {
+            let covered = (0..SIZE).contains(&x) && model[x as usize];
+            prop_assert_eq!(set.contains(x), covered, "contains({})", x);
+        }
+
+        let runs = runs(model);
+        let gaps: Vec<Range<i64>> = runs.windows(2).map(|w| w[0].end..w[1].start).collect();
+
+        prop_assert_eq!(set.iter().collect::<Vec<_>>(), runs.clone());
+        prop_assert_eq!(set.gaps().collect::<Vec<_>>(), gaps);
+        prop_assert_eq!(set.range_count(), runs.len());
+        prop_assert_eq!(set.is_empty(), runs.is_empty());
+        prop_assert_eq!(
+            set.covered_length(),
+            model.iter().filter(|c| **c).count() as i64
+        );
+
+        for run in &runs {
+            for x in run.clone() {
+                prop_assert_eq!(set.range_of(x), Some(run.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn model_of(ranges: &[Range<i64>]) -> Vec<bool> {
+        let mut model = vec![false; SIZE as usize];
+
+        for range in ranges {
+            apply(&mut model, range, true);
+        }
+
+        model
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(
+            operations in prop::collection::vec(operation(), 0..40),
+            other in prop::collection::vec(range(), 0..8),
+        ) {
+            let mut set = IntervalSet::new();
+            let mut model = vec![false; SIZE as usize];
+
+            let other_set: IntervalSet<i64> = other.iter().cloned().collect();
+            let other_model = model_of(&other);
+            check(&other_set, &other_model)?;
+
+            for operation in &operations {
+                match operation {
+                    Operation::Insert(range) => {
+                        set.insert(range.clone());
+                        apply(&mut model, range, true);
+                    }
+                    Operation::Remove(range) => {
+                        set.remove(range.clone());
+                        apply(&mut model, range, false);
+                    }
+                }
+
+                check(&set, &model)?;
+
+                let both: Vec<bool> = model.iter().zip(&other_model).map(|(a, b)| *a && *b).collect();
+                let only: Vec<bool> = model.iter().zip(&other_model).map(|(a, b)| *a && !*b).collect();
+                let either: Vec<bool> = model.iter().zip(&other_model).map(|(a, b)| *a || *b).collect();
+
+                check(&set.intersection(&other_set), &both)?;
+                check(&set.difference(&other_set), &only)?;
+                check(&set.union(&other_set), &either)?;
+            }
+        }
+    }
+
+    #[test]
+    fn coalesces_touching_ranges() {
+        let mut set = IntervalSet::new();
+        set.insert(0..5);
+        set.insert(10..15);
+        set.insert(5..10);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..15]);
+
+        set.remove(5..6);
+        set.insert(5..6);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..15]);
+        assert_eq!(set.gaps().count(), 0);
+    }
+
+    #[test]
+    fn keeps_ranges_one_apart() {
+        let set: IntervalSet<i64> = [0..5, 6..10].into_iter().collect();
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..5, 6..10]);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), vec![5..6]);
+        assert_eq!(set.covered_length(), 9);
+    }
+}
@@ -0,0 +1,263 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Numeric type usable as a point coordinate. Distances are measured in `f64`, which is
+/// exact for squared integer distances up to 2^53.
+pub trait Coordinate: Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i32, i64, isize, u32, u64, usize, f32, f64);
+
+pub fn squared_distance<T: Coordinate, const D: usize>(a: &[T; D], b: &[T; D]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| {
+            let diff = a.to_f64() - b.to_f64();
+            diff * diff
+        })
+        .sum()
+}
+
+/// Point found by a search, ordered by distance and then by index so ties are stable.
+#[derive(Debug, Clone, Copy)]
+pub struct Neighbour {
+    pub index: usize,
+    pub squared_distance: f64,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.squared_distance
+            .total_cmp(&other.squared_distance)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Static k-d tree over points in `D` dimensions.
+#[derive(Debug, Clone)]
+pub struct KdTree<T: Coordinate, const D: usize> {
+    points: Vec<[T; D]>,
+    // Point indices laid out so the median of every subtree slice is its root
+    order: Vec<usize>,
+}
+
+impl<T: Coordinate, const D: usize> KdTree<T, D> {
+    pub fn new(points: Vec<[T; D]>) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut order, 0);
+
+        KdTree { points, order }
+    }
+
+    pub fn points(&self) -> &[[T; D]] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The `k` points closest to `query`, nearest first.
+    pub fn nearest(&self, query: &[T; D], k: usize) -> Vec<Neighbour> {
+        self.search(query, k, None)
+    }
+
+    /// The `k` points closest to the point at `index`, excluding the point itself.
+    pub fn neighbours_of(&self, index: usize, k: usize) -> Vec<Neighbour> {
+        self.search(&self.points[index], k, Some(index))
+    }
+
+    /// All pairs of distinct points by increasing distance, computed lazily.
+    pub fn closest_pairs(&self) -> ClosestPairs<'_, T, D> {
+        ClosestPairs::new(self)
+    }
+
+    fn search(&self, query: &[T; D], k: usize, exclude: Option<usize>) -> Vec<Neighbour> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+
+        if k > 0 {
+            self.search_node(query, k, exclude, 0, self.order.len(), 0, &mut best);
+        }
+
+        best.into_sorted_vec()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn search_node(
+        &self,
+        query: &[T; D],
+        k: usize,
+        exclude: Option<usize>,
+        lo: usize,
+        hi: usize,
+        axis: usize,
+        best: &mut BinaryHeap<Neighbour>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        if exclude != Some(index) {
+            best.push(Neighbour {
+                index,
+                squared_distance: squared_distance(query, point),
+            });
+
+            if best.len() > k {
+                best.pop();
+            }
+        }
+
+        let diff = query[axis].to_f64() - point[axis].to_f64();
+        let next_axis = (axis + 1) % D;
+
+        let (near, far) = if diff < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.search_node(query, k, exclude, near.0, near.1, next_axis, best);
+
+        // Points on the splitting plane can still tie with the worst one, so only prune
+        // when the other side is strictly further away
+        let worst = best.peek().map(|n| n.squared_distance);
+
+        if best.len() < k || worst.is_some_and(|w| diff * diff <= w) {
+            self.search_node(query, k, exclude, far.0, far.1, next_axis, best);
+        }
+    }
+}
+
+fn build<T: Coordinate, const D: usize>(points: &[[T; D]], order: &mut [usize], axis: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let mid = order.len() / 2;
+
+    order.select_nth_unstable_by(mid, |a, b| {
+        points[*a][axis]
+            .to_f64()
+            .total_cmp(&points[*b][axis].to_f64())
+    });
+
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, (axis + 1) % D);
+    build(points, &mut right[1..], (axis + 1) % D);
+}
+
+/// Iterator over `(a, b, squared_distance)` with `a < b`, by increasing distance and then
+/// by `(a, b)`.
+///
+/// Every point keeps a cursor into its own neighbour list, fetched from the tree in
+/// batches of doubling size, and a heap picks the point whose next neighbour is closest.
+pub struct ClosestPairs<'a, T: Coordinate, const D: usize> {
+    tree: &'a KdTree<T, D>,
+    neighbours: Vec<Vec<Neighbour>>,
+    cursors: Vec<usize>,
+    heap: BinaryHeap<Reverse<(Neighbour, usize)>>,
+}
+
+impl<'a, T: Coordinate, const D: usize> ClosestPairs<'a, T, D> {
+    const INITIAL_BATCH: usize = 4;
+
+    fn new(tree: &'a KdTree<T, D>) -> Self {
+        let mut pairs = ClosestPairs {
+            tree,
+            neighbours: vec![Vec::new(); tree.len()],
+            cursors: vec![0; tree.len()],
+            heap: BinaryHeap::new(),
+        };
+
+        for index in 0..tree.len() {
+            pairs.push_next(index);
+        }
+
+        pairs
+    }
+
+    fn push_next(&mut self, index: usize) {
+        let cursor = self.cursors[index];
+
+        if cursor == self.neighbours[index].len() {
+            let fetched = self.neighbours[index].len();
+
+            if fetched == self.tree.len() - 1 && fetched > 0 {
+                return;
+            }
+
+            let batch = (fetched * 2).max(Self::INITIAL_BATCH);
+            self.neighbours[index] = self.tree.neighbours_of(index, batch);
+
+            if cursor == self.neighbours[index].len() {
+                return;
+            }
+        }
+
+        let neighbour = self.neighbours[index][cursor];
+
+        // Keyed by the pair with its smaller index first, so both directions of a pair
+        // come out of the heap next to each other
+        let key = Neighbour {
+            index: index.min(neighbour.index) * self.tree.len() + index.max(neighbour.index),
+            squared_distance: neighbour.squared_distance,
+        };
+
+        self.heap.push(Reverse((key, index)));
+    }
+}
+
+impl<T: Coordinate, const D: usize> Iterator for ClosestPairs<'_, T, D> {
+    type Item = (usize, usize, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((key, index)) = self.heap.pop()?;
+            let other = self.neighbours[index][self.cursors[index]].index;
+
+            self.cursors[index] += 1;
+            self.push_next(index);
+
+            // Every pair is found from both of its points, only the smaller one reports it
+            if index < other {
+                return Some((index, other, key.squared_distance));
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+pub mod automaton;
+pub mod bits;
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod ilp;
+pub mod input;
+pub mod interval;
+pub mod kd_tree;
+pub mod linear;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod partial_order;
+pub mod polygon;
+pub mod region;
+pub mod sokoban;
+pub mod text_grid;
+pub mod trie;
+pub mod union_find;
+pub mod visualize;
@@ -0,0 +1,413 @@
+use crate::math::{crt, extended_gcd, gcd, lcm};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Exact rational number, always kept reduced with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Fraction {
+    pub const ZERO: Fraction = Fraction {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    pub const ONE: Fraction = Fraction {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        if denominator == 0 {
+            panic!("Fraction with zero denominator");
+        }
+
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+
+        Fraction {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    pub fn floor(&self) -> i128 {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    pub fn ceil(&self) -> i128 {
+        -(-self.numerator).div_euclid(self.denominator)
+    }
+}
+
+impl From<i128> for Fraction {
+    fn from(value: i128) -> Self {
+        Fraction {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Fraction {
+    type Output = Fraction;
+
+    fn add(self, other: Fraction) -> Fraction {
+        let denominator = lcm(self.denominator, other.denominator);
+
+        Fraction::new(
+            self.numerator * (denominator / self.denominator)
+                + other.numerator * (denominator / other.denominator),
+            denominator,
+        )
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Fraction;
+
+    fn sub(self, other: Fraction) -> Fraction {
+        self + (-other)
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Fraction;
+
+    fn mul(self, other: Fraction) -> Fraction {
+        // Cross reduction first keeps the intermediate values small
+        let a = gcd(self.numerator, other.denominator).max(1);
+        let b = gcd(other.numerator, self.denominator).max(1);
+
+        Fraction::new(
+            (self.numerator / a) * (other.numerator / b),
+            (self.denominator / b) * (other.denominator / a),
+        )
+    }
+}
+
+impl Div for Fraction {
+    type Output = Fraction;
+
+    fn div(self, other: Fraction) -> Fraction {
+        Fraction::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        Fraction {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self - *other).numerator.cmp(&0)
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// All solutions of a linear system: `particular + sum(t[k] * directions[k])` where
+/// `t[k]` is the value chosen for the variable `free_variables[k]`.
+#[derive(Debug, Clone)]
+pub struct SolutionSpace {
+    pub particular: Vec<Fraction>,
+    pub free_variables: Vec<usize>,
+    pub directions: Vec<Vec<Fraction>>,
+}
+
+impl SolutionSpace {
+    /// Rank of the coefficient matrix.
+    pub fn rank(&self) -> usize {
+        self.particular.len() - self.free_variables.len()
+    }
+
+    pub fn unique(&self) -> Option<&[Fraction]> {
+        self.free_variables
+            .is_empty()
+            .then_some(self.particular.as_slice())
+    }
+
+    /// The solution with the given values of the free variables.
+    pub fn evaluate(&self, free_values: &[Fraction]) -> Vec<Fraction> {
+        let mut result = self.particular.clone();
+
+        for (direction, value) in self.directions.iter().zip(free_values) {
+            for (x, d) in result.iter_mut().zip(direction) {
+                *x = *x + *d * *value;
+            }
+        }
+
+        result
+    }
+
+    /// Finds the non-negative integer solution with the lowest `cost · x`. Returns `None`
+    /// if there is no such solution or the cost is not bounded from below. With more than
+    /// one free variable, non-negativity has to bound every free variable, which are then
+    /// searched with [`crate::ilp::minimize_in`]. Otherwise the first unbounded one is
+    /// returned as the error, [`crate::ilp::minimize_in`] can search within given bounds.
+    pub fn min_non_negative_integer(
+        &self,
+        cost: &[i128],
+    ) -> Result<Option<(i128, Vec<i128>)>, UnboundedFreeVariable> {
+        let total_cost = |x: &[i128]| x.iter().zip(cost).map(|(x, c)| x * c).sum::<i128>();
+
+        let x = match self.free_variables.len() {
+            0 => self
+                .particular
+                .iter()
+                .map(|x| x.to_integer().filter(|x| *x >= 0))
+                .collect::<Option<Vec<i128>>>(),
+            1 => self.min_along_direction(cost).map(|t| {
+                self.evaluate(&[Fraction::from(t)])
+                    .iter()
+                    .map(|x| x.to_integer().unwrap())
+                    .collect()
+            }),
+            _ => {
+                let mut upper_bounds = vec![i128::MAX; self.particular.len()];
+
+                for (k, variable) in self.free_variables.iter().enumerate() {
+                    upper_bounds[*variable] = self
+                        .free_variable_bound(k)
+                        .ok_or(UnboundedFreeVariable(*variable))?;
+                }
+
+                return Ok(crate::ilp::minimize_in(self, cost, &upper_bounds));
+            }
+        };
+
+        Ok(x.map(|x| (total_cost(&x), x)))
+    }
+
+    // Largest value of the free variable k that keeps some x[i] non-negative whatever the
+    // other free variables are, which needs x[i] to only shrink when any of them grows
+    fn free_variable_bound(&self, k: usize) -> Option<i128> {
+        (0..self.particular.len())
+            .filter(|i| {
+                self.directions[k][*i] < Fraction::ZERO
+                    && self.directions.iter().all(|d| d[*i] <= Fraction::ZERO)
+            })
+            .map(|i| (self.particular[i] / -self.directions[k][i]).floor())
+            .min()
+    }
+
+    // Value of the single free variable giving the cheapest non-negative integer solution
+    fn min_along_direction(&self, cost: &[i128]) -> Option<i128> {
+        let direction = &self.directions[0];
+
+        // Every x[i] = p[i] + t * d[i] has to be an integer, which is a congruence on t
+        let mut congruences = Vec::new();
+
+        for (p, d) in self.particular.iter().zip(direction) {
+            let common_denominator = lcm(p.denominator(), d.denominator());
+            let a = p.numerator() * (common_denominator / p.denominator());
+            let c = d.numerator() * (common_denominator / d.denominator());
+
+            // t * c = -a (mod common_denominator)
+            let (g, inverse, _) = extended_gcd(c, common_denominator);
+
+            if a.rem_euclid(g) != 0 {
+                return None;
+            }
+
+            let modulus = common_denominator / g;
+            congruences.push(((-a / g * inverse).rem_euclid(modulus), modulus));
+        }
+
+        let (residue, modulus) = crt(&congruences)?;
+
+        // Every x[i] >= 0 bounds t from one side
+        let mut lower: Option<i128> = None;
+        let mut upper: Option<i128> = None;
+
+        for (p, d) in self.particular.iter().zip(direction) {
+            match d.numerator().cmp(&0) {
+                Ordering::Greater => {
+                    let bound = (-*p / *d).ceil();
+                    lower = Some(lower.map_or(bound, |l| l.max(bound)));
+                }
+                Ordering::Less => {
+                    let bound = (-*p / *d).floor();
+                    upper = Some(upper.map_or(bound, |u| u.min(bound)));
+                }
+                Ordering::Equal if *p < Fraction::ZERO => return None,
+                Ordering::Equal => {}
+            }
+        }
+
+        let slope = direction
+            .iter()
+            .zip(cost)
+            .fold(Fraction::ZERO, |agg, (d, c)| agg + *d * Fraction::from(*c));
+
+        // Smallest t >= lower (or largest t <= upper) in the congruence class
+        let from_lower = lower.map(|l| l + (residue - l).rem_euclid(modulus));
+        let from_upper = upper.map(|u| u - (u - residue).rem_euclid(modulus));
+
+        let t = if slope > Fraction::ZERO {
+            from_lower?
+        } else if slope < Fraction::ZERO {
+            from_upper?
+        } else {
+            from_lower.or(from_upper).unwrap_or(residue)
+        };
+
+        match (lower, upper) {
+            (Some(l), _) if t < l => None,
+            (_, Some(u)) if t > u => None,
+            _ => Some(t),
+        }
+    }
+}
+
+/// Free variable that the non-negative solutions don't bound, so a search over them
+/// needs upper bounds to be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnboundedFreeVariable(pub usize);
+
+impl fmt::Display for UnboundedFreeVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Variable {} can grow without bound in the non-negative solutions",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnboundedFreeVariable {}
+
+/// Solves `coefficients · x = constants` exactly with Gaussian elimination. Returns `None`
+/// if the system is inconsistent.
+pub fn solve(coefficients: &[Vec<i128>], constants: &[i128]) -> Option<SolutionSpace> {
+    let variables = coefficients.first().map_or(0, |row| row.len());
+
+    let mut matrix: Vec<Vec<Fraction>> = coefficients
+        .iter()
+        .zip(constants)
+        .map(|(row, constant)| {
+            row.iter()
+                .chain(std::iter::once(constant))
+                .map(|x| Fraction::from(*x))
+                .collect()
+        })
+        .collect();
+
+    // Reduced row echelon form, remembering which column every pivot is in
+    let mut pivot_columns: Vec<usize> = Vec::new();
+
+    for column in 0..variables {
+        let row = pivot_columns.len();
+
+        let Some(pivot) = (row..matrix.len()).find(|r| !matrix[*r][column].is_zero()) else {
+            continue;
+        };
+
+        matrix.swap(row, pivot);
+
+        let pivot_value = matrix[row][column];
+        for x in matrix[row].iter_mut() {
+            *x = *x / pivot_value;
+        }
+
+        let pivot_row = matrix[row].clone();
+
+        for (other, other_row) in matrix.iter_mut().enumerate() {
+            let factor = other_row[column];
+
+            if other != row && !factor.is_zero() {
+                for (x, p) in other_row.iter_mut().zip(pivot_row.iter()).skip(column) {
+                    *x = *x - factor * *p;
+                }
+            }
+        }
+
+        pivot_columns.push(column);
+    }
+
+    // Rows without a pivot read 0 = constant
+    if matrix[pivot_columns.len()..]
+        .iter()
+        .any(|row| !row[variables].is_zero())
+    {
+        return None;
+    }
+
+    let free_variables: Vec<usize> = (0..variables)
+        .filter(|c| !pivot_columns.contains(c))
+        .collect();
+
+    let mut particular = vec![Fraction::ZERO; variables];
+
+    for (row, column) in pivot_columns.iter().enumerate() {
+        particular[*column] = matrix[row][variables];
+    }
+
+    let directions = free_variables
+        .iter()
+        .map(|free| {
+            let mut direction = vec![Fraction::ZERO; variables];
+            direction[*free] = Fraction::ONE;
+
+            for (row, column) in pivot_columns.iter().enumerate() {
+                direction[*column] = -matrix[row][*free];
+            }
+
+            direction
+        })
+        .collect();
+
+    Some(SolutionSpace {
+        particular,
+        free_variables,
+        directions,
+    })
+}
@@ -0,0 +1,163 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn abs<T: Integer>(x: T) -> T {
+    if x < T::ZERO {
+        T::ZERO - x
+    } else {
+        x
+    }
+}
+
+/// Greatest common divisor, always non-negative.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let mut a = abs(a);
+    let mut b = abs(b);
+
+    while b != T::ZERO {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Least common multiple, always non-negative. It is zero if any of the inputs is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    abs(a / gcd(a, b) * b)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Computes `a * b mod modulus` in `[0, modulus)` without overflowing, even when
+/// the product does not fit into `i128`.
+pub fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    let a = a.rem_euclid(modulus);
+    let b = b.rem_euclid(modulus);
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Double-and-add keeps every intermediate value below 2 * modulus
+    let modulus = modulus as u128;
+    let (mut a, mut b) = (a as u128, b as u128);
+    let mut result: u128 = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % modulus;
+        }
+
+        a = (a + a) % modulus;
+        b >>= 1;
+    }
+
+    result as i128
+}
+
+/// Computes `base ^ exponent mod modulus`.
+pub fn mod_pow(base: i128, exponent: u128, modulus: i128) -> i128 {
+    let mut result = 1 % modulus;
+    let mut base = base.rem_euclid(modulus);
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/// Solves the system `x = residue (mod modulus)` for all `(residue, modulus)` pairs.
+/// The moduli do not need to be coprime. Returns `(x, m)` where `x` is the smallest
+/// non-negative solution and all solutions are `x + k * m`. Returns `None` if the
+/// congruences contradict each other, and also if `m` would not fit in an `i128`.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut result: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for (residue, m) in congruences {
+        // x = result + modulus * k and we need modulus * k = residue - result (mod m)
+        let (g, p, _) = extended_gcd(modulus, *m);
+        let difference = residue - result;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        let m_g = m / g;
+        let k = mul_mod(difference / g, p, m_g);
+        // The combined modulus is the lcm of all moduli so far, which can overflow
+        let new_modulus = modulus.checked_mul(m_g)?;
+
+        result = (result + mul_mod(modulus, k, new_modulus)).rem_euclid(new_modulus);
+        modulus = new_modulus;
+    }
+
+    Some((result, modulus))
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache of results of a function keyed by its arguments, usually as a tuple. It lives as
+/// long as the caller keeps it, so nothing leaks between runs, and counts how often a
+/// result was reused.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            values: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Cached result for `key`, running `compute` on a miss. `compute` gets the memo itself
+    /// so a recursive function can look up its smaller cases.
+    pub fn get(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.values.insert(key, value.clone());
+
+        value
+    }
+
+    /// Number of lookups answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups that had to compute their result.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Forgets all results and statistics.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
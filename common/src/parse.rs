@@ -0,0 +1,300 @@
+use std::fmt;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+/// Input that doesn't match the expected format.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub pattern: String,
+    pub input: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    fn new(pattern: &str, input: &str, reason: String) -> Self {
+        ParseError {
+            pattern: pattern.to_string(),
+            input: input.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Cannot parse {:?} as {:?}: {}",
+            self.input, self.pattern, self.reason
+        )
+    }
+}
+
+// Unwrapping a result shows the same message as printing the error
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Value that can be read from a field captured by a pattern.
+pub trait FromField<'a>: Sized {
+    fn from_field(field: &'a str) -> Result<Self, String>;
+}
+
+macro_rules! impl_from_field {
+    ($($t:ty),*) => {
+        $(
+            impl FromField<'_> for $t {
+                fn from_field(field: &str) -> Result<Self, String> {
+                    field
+                        .trim()
+                        .parse()
+                        .map_err(|e| format!("{:?} is not {}: {}", field, stringify!($t), e))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool);
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(field: &'a str) -> Result<Self, String> {
+        Ok(field)
+    }
+}
+
+impl FromField<'_> for String {
+    fn from_field(field: &str) -> Result<Self, String> {
+        Ok(field.to_string())
+    }
+}
+
+impl FromField<'_> for char {
+    fn from_field(field: &str) -> Result<Self, String> {
+        let mut chars = field.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(format!("{:?} is not a single character", field)),
+        }
+    }
+}
+
+/// Items separated by commas and/or whitespace.
+impl<'a, T: FromField<'a>> FromField<'a> for Vec<T> {
+    fn from_field(field: &'a str) -> Result<Self, String> {
+        field
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+            .map(T::from_field)
+            .collect()
+    }
+}
+
+/// Matches `input` against a pattern of literal text with `{}` placeholders and returns
+/// the text captured by every placeholder. A placeholder captures up to the first
+/// occurrence of the literal after it, or up to the literal ending the pattern. Only `{}`
+/// is a placeholder and there is no escaping, any other brace is literal text, so
+/// `{{}}` is a placeholder between a literal `{` and `}`.
+pub fn scan<'a>(pattern: &str, input: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let error = |reason: String| ParseError::new(pattern, input, reason);
+
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    let mut rest = input
+        .strip_prefix(literals[0])
+        .ok_or_else(|| error(format!("expected it to start with {:?}", literals[0])))?;
+
+    let mut fields = Vec::with_capacity(literals.len() - 1);
+
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        if i == literals.len() - 1 {
+            let field = rest
+                .strip_suffix(literal)
+                .ok_or_else(|| error(format!("expected it to end with {:?}", literal)))?;
+
+            fields.push(field);
+            rest = "";
+        } else if literal.is_empty() {
+            return Err(error("two placeholders next to each other".to_string()));
+        } else {
+            let (field, after) = rest
+                .split_once(literal)
+                .ok_or_else(|| error(format!("expected {:?} after field {}", literal, i)))?;
+
+            fields.push(field);
+            rest = after;
+        }
+    }
+
+    if !rest.is_empty() {
+        return Err(error(format!("unexpected {:?} at the end", rest)));
+    }
+
+    Ok(fields)
+}
+
+/// Typed access to the fields of a scanned input, used by [`parse!`](crate::parse!).
+pub struct Fields<'a, 'p> {
+    pattern: &'p str,
+    input: &'a str,
+    fields: Vec<&'a str>,
+    next: usize,
+}
+
+impl<'a, 'p> Fields<'a, 'p> {
+    pub fn new(pattern: &'p str, input: &'a str) -> Result<Self, ParseError> {
+        Ok(Fields {
+            pattern,
+            input,
+            fields: scan(pattern, input)?,
+            next: 0,
+        })
+    }
+
+    pub fn next_field<T: FromField<'a>>(&mut self) -> Result<T, ParseError> {
+        let field = self.fields.get(self.next).ok_or_else(|| {
+            ParseError::new(
+                self.pattern,
+                self.input,
+                format!("pattern has only {} fields", self.fields.len()),
+            )
+        })?;
+
+        self.next += 1;
+
+        T::from_field(field).map_err(|reason| {
+            ParseError::new(
+                self.pattern,
+                self.input,
+                format!("field {}: {}", self.next, reason),
+            )
+        })
+    }
+
+    /// Checks that every field was used.
+    pub fn finish(self) -> Result<(), ParseError> {
+        if self.next == self.fields.len() {
+            Ok(())
+        } else {
+            Err(ParseError::new(
+                self.pattern,
+                self.input,
+                format!(
+                    "pattern has {} fields but {} types are given",
+                    self.fields.len(),
+                    self.next
+                ),
+            ))
+        }
+    }
+}
+
+/// Parses `input` by a pattern with `{}` placeholders into a value of every given type,
+/// e.g. `parse!(line, "Button A: X+{}, Y+{}", i64, i64)` gives `Ok((94, 34))`. A single
+/// type gives the value itself instead of a tuple.
+#[macro_export]
+macro_rules! parse {
+    ($input:expr, $pattern:expr, $t:ty $(,)?) => {
+        $crate::parse::Fields::new($pattern, $input).and_then(|mut fields| {
+            let value = fields.next_field::<$t>()?;
+            fields.finish().map(|_| value)
+        })
+    };
+    ($input:expr, $pattern:expr, $($t:ty),+ $(,)?) => {
+        $crate::parse::Fields::new($pattern, $input).and_then(|mut fields| {
+            let values = ($(fields.next_field::<$t>()?,)+);
+            fields.finish().map(|_| values)
+        })
+    };
+}
+
+/// Integers found by [`ints`] that can't be used as requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntsError {
+    /// A number too large or too small for the integer type, as written in the input.
+    Overflow(String),
+    /// A negative number read as an unsigned type.
+    Negative(String),
+    /// A different number of integers than [`ints_n`] was asked for.
+    Count { expected: usize, found: usize },
+}
+
+impl fmt::Display for IntsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntsError::Overflow(number) => write!(f, "{} does not fit the integer type", number),
+            IntsError::Negative(number) => write!(f, "{} is negative", number),
+            IntsError::Count { expected, found } => {
+                write!(f, "expected {} integers but found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntsError {}
+
+/// Every integer in `line` in order, ignoring whatever text is around them, e.g.
+/// `p=0,4 v=3,-3` gives `[0, 4, 3, -3]`. A minus sign belongs to the number after it unless
+/// it directly follows a digit, so the range `10-20` gives `[10, 20]`.
+pub fn ints<T>(line: &str) -> Result<Vec<T>, IntsError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    numbers(line).map(parse_int).collect()
+}
+
+/// Exactly `N` integers found in `line` like [`ints`], e.g.
+/// `let [x, y] = ints_n::<i64, 2>(line)?;`.
+pub fn ints_n<T, const N: usize>(line: &str) -> Result<[T; N], IntsError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let found = ints(line)?;
+
+    found.try_into().map_err(|found: Vec<T>| IntsError::Count {
+        expected: N,
+        found: found.len(),
+    })
+}
+
+fn numbers(line: &str) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut pos = 0;
+
+    std::iter::from_fn(move || {
+        while pos < bytes.len() && !bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+
+        if pos == bytes.len() {
+            return None;
+        }
+
+        let start =
+            if pos > 0 && bytes[pos - 1] == b'-' && (pos < 2 || !bytes[pos - 2].is_ascii_digit()) {
+                pos - 1
+            } else {
+                pos
+            };
+
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+
+        Some(&line[start..pos])
+    })
+}
+
+fn parse_int<T: FromStr<Err = ParseIntError>>(number: &str) -> Result<T, IntsError> {
+    number.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+            IntsError::Overflow(number.to_string())
+        }
+        // Only the sign can make a run of digits invalid
+        _ => IntsError::Negative(number.to_string()),
+    })
+}
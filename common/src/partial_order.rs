@@ -0,0 +1,95 @@
+use crate::graph::DirectedGraph;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError<T> {
+    /// The rules among the items contradict each other, the items are given in cycle order.
+    Cycle(Vec<T>),
+    /// Nothing decides which of the two neighbouring items comes first.
+    Ambiguous(T, T),
+}
+
+/// Precedence relation given by pairs of items where the first has to come before the
+/// second.
+#[derive(Debug, Clone)]
+pub struct Precedence<T> {
+    successors: HashMap<T, HashSet<T>>,
+}
+
+impl<T: Eq + Hash + Clone> Default for Precedence<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash + Clone> Precedence<T> {
+    pub fn new() -> Self {
+        Precedence {
+            successors: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, before: T, after: T) {
+        self.successors.entry(before).or_default().insert(after);
+    }
+
+    /// Whether a rule says `before` comes before `after`.
+    pub fn precedes(&self, before: &T, after: &T) -> bool {
+        self.successors
+            .get(before)
+            .is_some_and(|successors| successors.contains(after))
+    }
+
+    /// Whether the sequence breaks none of the rules between its items.
+    pub fn respects(&self, sequence: &[T]) -> bool {
+        sequence.iter().enumerate().all(|(i, later)| {
+            sequence[..i]
+                .iter()
+                .all(|earlier| !self.precedes(later, earlier))
+        })
+    }
+
+    /// The only order of the items respecting the rules between them.
+    pub fn order(&self, items: &[T]) -> Result<Vec<T>, OrderError<T>> {
+        let mut graph = DirectedGraph::new();
+
+        for item in items {
+            graph.add_node(item.clone());
+        }
+
+        for before in items {
+            for after in items {
+                if self.precedes(before, after) {
+                    graph.add_edge(before.clone(), after.clone());
+                }
+            }
+        }
+
+        let order = graph.topological_order().map_err(|cycle| {
+            OrderError::Cycle(cycle.0.iter().map(|n| graph.label(*n).clone()).collect())
+        })?;
+
+        // A topological order is the only one exactly if a rule links every two
+        // neighbouring items
+        let order: Vec<T> = order.iter().map(|n| graph.label(*n).clone()).collect();
+
+        if let Some(pair) = order.windows(2).find(|w| !self.precedes(&w[0], &w[1])) {
+            return Err(OrderError::Ambiguous(pair[0].clone(), pair[1].clone()));
+        }
+
+        Ok(order)
+    }
+}
+
+impl<T: Eq + Hash + Clone> FromIterator<(T, T)> for Precedence<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut precedence = Precedence::new();
+
+        for (before, after) in iter {
+            precedence.add(before, after);
+        }
+
+        precedence
+    }
+}
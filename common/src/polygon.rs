@@ -0,0 +1,200 @@
+use crate::math::gcd;
+
+/// Twice the area of a simple polygon given by its vertices in order (shoelace formula).
+pub fn doubled_area(vertices: &[(i64, i64)]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<i64>()
+        .abs()
+}
+
+/// Number of lattice points on the boundary of a polygon with lattice vertices.
+pub fn boundary_points(vertices: &[(i64, i64)]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| gcd(x2 - x1, y2 - y1))
+        .sum()
+}
+
+/// Number of lattice points strictly inside a polygon with lattice vertices (Pick's
+/// theorem).
+pub fn interior_points(vertices: &[(i64, i64)]) -> i64 {
+    (doubled_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+/// Number of lattice points inside or on the boundary of a polygon with lattice vertices.
+pub fn lattice_points(vertices: &[(i64, i64)]) -> i64 {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+/// Polygon with axis-parallel edges between tile coordinates, covering every tile on its
+/// boundary or inside it.
+///
+/// Coordinates are compressed into blocks of tiles that are either all covered or all
+/// not, and a 2D prefix sum over the blocks answers rectangle queries in O(log n), or in
+/// O(1) for rectangles between two vertices.
+#[derive(Debug, Clone)]
+pub struct RectilinearPolygon {
+    // Block `i` spans the tiles `xs[i]..xs[i + 1]`, the last value only closes the range
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    // Number of covered blocks in `0..i` x `0..j`
+    prefix: Vec<Vec<usize>>,
+    // Block of every vertex, in the order they were given
+    vertex_blocks: Vec<(usize, usize)>,
+    tiles: i64,
+}
+
+impl RectilinearPolygon {
+    /// Creates the polygon from its corners in order. Panics if an edge is not
+    /// axis-parallel.
+    pub fn new(vertices: &[(i64, i64)]) -> Self {
+        let edges: Vec<((i64, i64), (i64, i64))> = vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+            .collect();
+
+        if edges.iter().any(|(a, b)| a.0 != b.0 && a.1 != b.1) {
+            panic!("Polygon edges have to be axis-parallel");
+        }
+
+        // Every corner starts a block one tile wide, so edges fill whole blocks
+        let breakpoints = |coord: fn(&(i64, i64)) -> i64| {
+            let mut values: Vec<i64> = vertices
+                .iter()
+                .flat_map(|v| [coord(v), coord(v) + 1])
+                .collect();
+            values.sort_unstable();
+            values.dedup();
+            values
+        };
+
+        let xs = breakpoints(|v| v.0);
+        let ys = breakpoints(|v| v.1);
+        let block_x = |x: i64| xs.binary_search(&x).unwrap();
+        let block_y = |y: i64| ys.binary_search(&y).unwrap();
+
+        let width = xs.len().saturating_sub(1);
+        let height = ys.len().saturating_sub(1);
+        let mut covered = vec![vec![false; height]; width];
+
+        for (a, b) in &edges {
+            for row in covered
+                .iter_mut()
+                .take(block_x(a.0.max(b.0)) + 1)
+                .skip(block_x(a.0.min(b.0)))
+            {
+                for cell in row
+                    .iter_mut()
+                    .take(block_y(a.1.max(b.1)) + 1)
+                    .skip(block_y(a.1.min(b.1)))
+                {
+                    *cell = true;
+                }
+            }
+        }
+
+        // Tiles off the boundary are inside if a ray towards lower x crosses an odd number
+        // of vertical edges, with the edges taken as half-open in y
+        for (j, y) in ys.iter().take(height).enumerate() {
+            let mut crossings: Vec<i64> = edges
+                .iter()
+                .filter(|(a, b)| a.0 == b.0 && a.1.min(b.1) <= *y && *y < a.1.max(b.1))
+                .map(|(a, _)| a.0)
+                .collect();
+            crossings.sort_unstable();
+
+            let mut crossed = 0;
+
+            for (i, x) in xs.iter().take(width).enumerate() {
+                while crossed < crossings.len() && crossings[crossed] < *x {
+                    crossed += 1;
+                }
+
+                if crossed % 2 == 1 {
+                    covered[i][j] = true;
+                }
+            }
+        }
+
+        let mut prefix = vec![vec![0; height + 1]; width + 1];
+        let mut tiles = 0;
+
+        for i in 0..width {
+            for j in 0..height {
+                let is_covered = covered[i][j];
+
+                if is_covered {
+                    tiles += (xs[i + 1] - xs[i]) * (ys[j + 1] - ys[j]);
+                }
+
+                prefix[i + 1][j + 1] =
+                    prefix[i][j + 1] + prefix[i + 1][j] - prefix[i][j] + usize::from(is_covered);
+            }
+        }
+
+        let vertex_blocks = vertices
+            .iter()
+            .map(|(x, y)| (block_x(*x), block_y(*y)))
+            .collect();
+
+        RectilinearPolygon {
+            xs,
+            ys,
+            prefix,
+            vertex_blocks,
+            tiles,
+        }
+    }
+
+    /// Number of tiles covered by the polygon.
+    pub fn tile_count(&self) -> i64 {
+        self.tiles
+    }
+
+    pub fn contains_tile(&self, tile: (i64, i64)) -> bool {
+        self.contains_rectangle(tile, tile)
+    }
+
+    /// Whether every tile of the rectangle with the given opposite corners is covered.
+    pub fn contains_rectangle(&self, a: (i64, i64), b: (i64, i64)) -> bool {
+        let (Some(x1), Some(x2), Some(y1), Some(y2)) = (
+            Self::block(&self.xs, a.0.min(b.0)),
+            Self::block(&self.xs, a.0.max(b.0)),
+            Self::block(&self.ys, a.1.min(b.1)),
+            Self::block(&self.ys, a.1.max(b.1)),
+        ) else {
+            return false;
+        };
+
+        self.covers_blocks(x1, x2, y1, y2)
+    }
+
+    /// Whether every tile of the rectangle with vertices `i` and `j` as opposite corners is
+    /// covered, without searching for the blocks of the corners.
+    pub fn contains_vertex_rectangle(&self, i: usize, j: usize) -> bool {
+        let (ax, ay) = self.vertex_blocks[i];
+        let (bx, by) = self.vertex_blocks[j];
+
+        self.covers_blocks(ax.min(bx), ax.max(bx), ay.min(by), ay.max(by))
+    }
+
+    // Whether all blocks in `x1..=x2` x `y1..=y2` are covered
+    fn covers_blocks(&self, x1: usize, x2: usize, y1: usize, y2: usize) -> bool {
+        let covered = self.prefix[x2 + 1][y2 + 1] + self.prefix[x1][y1]
+            - self.prefix[x1][y2 + 1]
+            - self.prefix[x2 + 1][y1];
+
+        covered == (x2 - x1 + 1) * (y2 - y1 + 1)
+    }
+
+    fn block(breakpoints: &[i64], value: i64) -> Option<usize> {
+        let after = breakpoints.partition_point(|b| *b <= value);
+
+        (after > 0 && after < breakpoints.len()).then(|| after - 1)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::grid::{Connectivity, DIAGONAL, ORTHOGONAL};
+use std::collections::VecDeque;
+
+/// Connected group of equivalent cells of a grid, with cells given as `(row, column)`.
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub cells: Vec<(usize, usize)>,
+    /// Number of cell edges not shared with another cell of the region.
+    pub perimeter: usize,
+    /// Number of corners of the region outline, which is also its number of straight sides.
+    pub corners: usize,
+    /// Top left and bottom right cell of the smallest rectangle containing the region.
+    pub bounding_box: ((usize, usize), (usize, usize)),
+    /// Number of separate areas of other cells completely enclosed by the region.
+    pub holes: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn sides(&self) -> usize {
+        self.corners
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Regions {
+    /// Index into `regions` for every cell of the grid.
+    pub labels: Vec<Vec<usize>>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn region_of(&self, cell: (usize, usize)) -> &Region {
+        &self.regions[self.labels[cell.0][cell.1]]
+    }
+}
+
+/// Splits the grid into regions of neighbouring cells for which `same` holds.
+pub fn find_regions<T>(
+    grid: &[Vec<T>],
+    connectivity: Connectivity,
+    same: impl Fn(&T, &T) -> bool,
+) -> Regions {
+    let mut labels: Vec<Vec<Option<usize>>> =
+        grid.iter().map(|row| vec![None; row.len()]).collect();
+    let mut all_cells = Vec::new();
+    let contains = |(r, c): (usize, usize)| r < grid.len() && c < grid[r].len();
+
+    for row in 0..grid.len() {
+        for column in 0..grid[row].len() {
+            if labels[row][column].is_some() {
+                continue;
+            }
+
+            let label = all_cells.len();
+            let mut cells = vec![(row, column)];
+            let mut queue = VecDeque::from([(row, column)]);
+            labels[row][column] = Some(label);
+
+            while let Some(cur) = queue.pop_front() {
+                for next in connectivity.neighbours(cur, contains) {
+                    if labels[next.0][next.1].is_none()
+                        && same(&grid[cur.0][cur.1], &grid[next.0][next.1])
+                    {
+                        labels[next.0][next.1] = Some(label);
+                        cells.push(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            all_cells.push(cells);
+        }
+    }
+
+    let labels: Vec<Vec<usize>> = labels
+        .into_iter()
+        .map(|row| row.into_iter().map(|label| label.unwrap()).collect())
+        .collect();
+
+    let regions = all_cells
+        .into_iter()
+        .enumerate()
+        .map(|(label, cells)| describe(&labels, label, cells, connectivity))
+        .collect();
+
+    Regions { labels, regions }
+}
+
+fn describe(
+    labels: &[Vec<usize>],
+    label: usize,
+    cells: Vec<(usize, usize)>,
+    connectivity: Connectivity,
+) -> Region {
+    let is_in = |row: isize, column: isize| {
+        row >= 0
+            && column >= 0
+            && labels
+                .get(row as usize)
+                .and_then(|r| r.get(column as usize))
+                .is_some_and(|l| *l == label)
+    };
+
+    let mut perimeter = 0;
+    let mut corners = 0;
+
+    for (row, column) in &cells {
+        let (r, c) = (*row as isize, *column as isize);
+
+        perimeter += ORTHOGONAL
+            .iter()
+            .filter(|(dr, dc)| !is_in(r + dr, c + dc))
+            .count();
+
+        // Every diagonal direction is a corner of the cell, which is a corner of the region
+        // if both sides next to it are outside, or both inside with the diagonal outside
+        for (dr, dc) in DIAGONAL {
+            let vertical = is_in(r + dr, c);
+            let horizontal = is_in(r, c + dc);
+
+            if (!vertical && !horizontal) || (vertical && horizontal && !is_in(r + dr, c + dc)) {
+                corners += 1;
+            }
+        }
+    }
+
+    let min_row = cells.iter().map(|c| c.0).min().unwrap();
+    let max_row = cells.iter().map(|c| c.0).max().unwrap();
+    let min_column = cells.iter().map(|c| c.1).min().unwrap();
+    let max_column = cells.iter().map(|c| c.1).max().unwrap();
+
+    // Other cells within the bounding box and a one cell border around it, where the
+    // components not connected to the border are the holes
+    let height = max_row - min_row + 3;
+    let width = max_column - min_column + 3;
+    let outside: Vec<Vec<bool>> = (0..height)
+        .map(|r| {
+            (0..width)
+                .map(|c| !is_in((r + min_row) as isize - 1, (c + min_column) as isize - 1))
+                .collect()
+        })
+        .collect();
+
+    let mut seen = vec![vec![false; width]; height];
+    let mut components = 0;
+    let contains = |(r, c): (usize, usize)| r < height && c < width;
+
+    for r in 0..height {
+        for c in 0..width {
+            if !outside[r][c] || seen[r][c] {
+                continue;
+            }
+
+            components += 1;
+            seen[r][c] = true;
+            let mut queue = VecDeque::from([(r, c)]);
+
+            while let Some(cur) = queue.pop_front() {
+                for next in connectivity.complement().neighbours(cur, contains) {
+                    if outside[next.0][next.1] && !seen[next.0][next.1] {
+                        seen[next.0][next.1] = true;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+    }
+
+    Region {
+        cells,
+        perimeter,
+        corners,
+        bounding_box: ((min_row, min_column), (max_row, max_column)),
+        // The first component found is the one around the border
+        holes: components - 1,
+    }
+}
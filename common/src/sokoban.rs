@@ -0,0 +1,304 @@
+use crate::grid::{Direction, Grid};
+use std::collections::HashSet;
+
+/// Objects that moved together in one push, in the order they were reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Push {
+    pub direction: Direction,
+    pub moved: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+    Object(usize),
+}
+
+/// Walls and rigid objects of any shape on a grid, where pushing an object also pushes
+/// everything in its way, unless something in the way is blocked by a wall or the edge.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    cells: Grid<Cell>,
+    objects: Vec<Vec<(usize, usize)>>,
+    // Only kept once recording is turned on with `with_history`
+    history: Option<Vec<Push>>,
+}
+
+impl Warehouse {
+    pub fn new(width: usize, height: usize) -> Self {
+        Warehouse {
+            cells: Grid::new(width, height, Cell::Empty),
+            objects: Vec::new(),
+            history: None,
+        }
+    }
+
+    /// Records every push from now on, so it can be undone.
+    pub fn with_history(mut self) -> Self {
+        self.history.get_or_insert_with(Vec::new);
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn add_wall(&mut self, pos: (usize, usize)) {
+        self.check_free(pos);
+        self.cells[pos] = Cell::Wall;
+    }
+
+    /// Adds an object covering `cells`, which don't have to be connected, and returns its
+    /// index.
+    pub fn add_object(&mut self, cells: Vec<(usize, usize)>) -> usize {
+        let object = self.objects.len();
+
+        for pos in &cells {
+            self.check_free(*pos);
+            self.cells[*pos] = Cell::Object(object);
+        }
+
+        self.objects.push(cells);
+        object
+    }
+
+    pub fn is_wall(&self, pos: (usize, usize)) -> bool {
+        self.cells[pos] == Cell::Wall
+    }
+
+    pub fn object_at(&self, pos: (usize, usize)) -> Option<usize> {
+        object_of(&self.cells[pos])
+    }
+
+    pub fn object_count(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Current cells of the object, in the order it was added with.
+    pub fn cells(&self, object: usize) -> &[(usize, usize)] {
+        &self.objects[object]
+    }
+
+    /// Pushes from `object` one step in `direction`. Returns what moved, starting with
+    /// `object` itself, or `None` if it is blocked and nothing moved.
+    pub fn push(&mut self, object: usize, direction: Direction) -> Option<Push> {
+        let mut moved = vec![object];
+        let mut reached = HashSet::from([object]);
+        let mut i = 0;
+
+        // Every object in the way of a moving one has to move too
+        while i < moved.len() {
+            for pos in &self.objects[moved[i]] {
+                match self
+                    .cells
+                    .step(*pos, direction)
+                    .map(|next| self.cells[next])
+                {
+                    None | Some(Cell::Wall) => return None,
+                    Some(Cell::Object(other)) if reached.insert(other) => moved.push(other),
+                    Some(_) => {}
+                }
+            }
+
+            i += 1;
+        }
+
+        self.shift(&moved, direction);
+        let push = Push { direction, moved };
+
+        if let Some(history) = &mut self.history {
+            history.push(push.clone());
+        }
+
+        Some(push)
+    }
+
+    /// Reverts the last recorded push and returns it. Returns `None` if there is none,
+    /// which is always the case without [`Warehouse::with_history`].
+    pub fn undo(&mut self) -> Option<Push> {
+        let push = self.history.as_mut()?.pop()?;
+
+        // The cells the objects came from are all free, as they left them themselves
+        self.shift(&push.moved, push.direction.opposite());
+
+        Some(push)
+    }
+
+    /// Every recorded push, oldest first.
+    pub fn history(&self) -> &[Push] {
+        self.history.as_deref().unwrap_or_default()
+    }
+
+    /// The warehouse drawn with one character per cell, with `object` giving the
+    /// character for a cell of an object.
+    pub fn render(
+        &self,
+        wall: char,
+        empty: char,
+        object: impl Fn(usize, (usize, usize)) -> char,
+    ) -> String {
+        let mut result = String::new();
+
+        for (pos, cell) in self.cells.iter() {
+            result.push(match cell {
+                Cell::Empty => empty,
+                Cell::Wall => wall,
+                Cell::Object(o) => object(*o, pos),
+            });
+
+            if pos.1 == self.width() - 1 {
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+
+    // Moves the objects without checking, all cells they move into have to be free or
+    // taken by one of them
+    fn shift(&mut self, objects: &[usize], direction: Direction) {
+        for object in objects {
+            for pos in &self.objects[*object] {
+                self.cells[*pos] = Cell::Empty;
+            }
+        }
+
+        for object in objects {
+            for pos in self.objects[*object].iter_mut() {
+                *pos = self.cells.step(*pos, direction).unwrap();
+                self.cells[*pos] = Cell::Object(*object);
+            }
+        }
+    }
+
+    fn check_free(&self, pos: (usize, usize)) {
+        assert!(
+            self.cells[pos] == Cell::Empty,
+            "Position ({}, {}) is already taken",
+            pos.0,
+            pos.1
+        );
+    }
+}
+
+/// Cell whose object differs between two steps of a [`Replay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub pos: (usize, usize),
+    pub before: Option<usize>,
+    pub after: Option<usize>,
+}
+
+/// Moves of one object through a warehouse that can be stepped through forwards and
+/// backwards. Step `n` is the state after the first `n` moves.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    warehouse: Warehouse,
+    pusher: usize,
+    moves: Vec<Direction>,
+    // Per move done so far, whether it pushed something (blocked moves leave no history)
+    pushed: Vec<bool>,
+}
+
+impl Replay {
+    /// Turns on the history of `warehouse`, which going backwards undoes.
+    pub fn new(warehouse: Warehouse, pusher: usize, moves: Vec<Direction>) -> Self {
+        Replay {
+            warehouse: warehouse.with_history(),
+            pusher,
+            moves,
+            pushed: Vec::new(),
+        }
+    }
+
+    /// Number of moves.
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Current step.
+    pub fn step(&self) -> usize {
+        self.pushed.len()
+    }
+
+    pub fn warehouse(&self) -> &Warehouse {
+        &self.warehouse
+    }
+
+    /// The object doing the moves.
+    pub fn pusher(&self) -> usize {
+        self.pusher
+    }
+
+    /// Does the next move. Returns `false` if all moves are done.
+    pub fn forward(&mut self) -> bool {
+        let Some(direction) = self.moves.get(self.step()) else {
+            return false;
+        };
+
+        let pushed = self.warehouse.push(self.pusher, *direction).is_some();
+        self.pushed.push(pushed);
+
+        true
+    }
+
+    /// Reverts the last move. Returns `false` if at the start.
+    pub fn backward(&mut self) -> bool {
+        match self.pushed.pop() {
+            Some(pushed) => {
+                if pushed {
+                    self.warehouse.undo();
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Goes forwards or backwards to step `step`, or as close as the moves allow.
+    pub fn seek(&mut self, step: usize) {
+        while self.step() < step && self.forward() {}
+        while self.step() > step && self.backward() {}
+    }
+
+    /// Cells that differ between steps `a` and `b`, row by row. The replay ends up at the
+    /// step it was at before.
+    pub fn diff(&mut self, a: usize, b: usize) -> Vec<Change> {
+        let current = self.step();
+
+        self.seek(a);
+        let before = self.warehouse.cells.clone();
+        self.seek(b);
+
+        let changes = before
+            .iter()
+            .zip(self.warehouse.cells.iter())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((pos, old), (_, new))| Change {
+                pos,
+                before: object_of(old),
+                after: object_of(new),
+            })
+            .collect();
+
+        self.seek(current);
+        changes
+    }
+}
+
+fn object_of(cell: &Cell) -> Option<usize> {
+    match cell {
+        Cell::Object(object) => Some(*object),
+        _ => None,
+    }
+}
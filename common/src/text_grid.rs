@@ -0,0 +1,114 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Rectangular view of text with every line padded by spaces to the longest one, for
+/// inputs laid out in aligned columns that have to be read sideways.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextGrid {
+    cells: Vec<Vec<char>>,
+    width: usize,
+}
+
+impl TextGrid {
+    pub fn new(text: &str) -> Self {
+        let mut cells: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let width = cells.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        for row in cells.iter_mut() {
+            row.resize(width, ' ');
+        }
+
+        TextGrid { cells, width }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> char {
+        self.cells[row][column]
+    }
+
+    pub fn row(&self, row: usize) -> String {
+        self.cells[row].iter().collect()
+    }
+
+    /// Characters of the column from top to bottom.
+    pub fn column(&self, column: usize) -> String {
+        self.cells.iter().map(|row| row[column]).collect()
+    }
+
+    pub fn is_blank_column(&self, column: usize) -> bool {
+        self.cells.iter().all(|row| row[column].is_whitespace())
+    }
+
+    /// Grid with rows and columns swapped, so the columns can be read as lines.
+    pub fn transpose(&self) -> TextGrid {
+        TextGrid {
+            cells: (0..self.width)
+                .map(|column| self.cells.iter().map(|row| row[column]).collect())
+                .collect(),
+            width: self.height(),
+        }
+    }
+
+    pub fn sub_grid(&self, rows: Range<usize>, columns: Range<usize>) -> TextGrid {
+        TextGrid {
+            width: columns.len(),
+            cells: self.cells[rows]
+                .iter()
+                .map(|row| row[columns.clone()].to_vec())
+                .collect(),
+        }
+    }
+
+    /// Parts of the grid between columns that are blank in every row, from left to right.
+    pub fn blocks(&self) -> Vec<TextGrid> {
+        let mut result = Vec::new();
+        let mut start = None;
+
+        for column in 0..=self.width {
+            if column == self.width || self.is_blank_column(column) {
+                if let Some(block_start) = start.take() {
+                    result.push(self.sub_grid(0..self.height(), block_start..column));
+                }
+            } else {
+                start.get_or_insert(column);
+            }
+        }
+
+        result
+    }
+
+    /// Number written in every row, reading its digits left to right and skipping blanks,
+    /// from the top row down. Blank rows are left out.
+    pub fn numbers_by_row<T: FromStr>(&self) -> Result<Vec<T>, T::Err> {
+        (0..self.height())
+            .map(|row| self.row(row))
+            .filter_map(|line| read_number(line.chars()))
+            .collect()
+    }
+
+    /// Number written in every column, reading its digits top to bottom and skipping
+    /// blanks, from the rightmost column to the left. Blank columns are left out.
+    pub fn numbers_by_column<T: FromStr>(&self) -> Result<Vec<T>, T::Err> {
+        (0..self.width)
+            .rev()
+            .filter_map(|column| read_number(self.cells.iter().map(|row| row[column])))
+            .collect()
+    }
+}
+
+fn read_number<T: FromStr>(chars: impl Iterator<Item = char>) -> Option<Result<T, T::Err>> {
+    let digits: String = chars.filter(|c| !c.is_whitespace()).collect();
+
+    if digits.is_empty() {
+        None
+    } else {
+        Some(digits.parse())
+    }
+}
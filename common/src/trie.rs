@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    children: HashMap<T, usize>,
+    is_word: bool,
+}
+
+impl<T> Node<T> {
+    fn new() -> Self {
+        Node {
+            children: HashMap::new(),
+            is_word: false,
+        }
+    }
+}
+
+/// Prefix tree over sequences of `T`, e.g. the bytes of strings.
+#[derive(Debug, Clone)]
+pub struct Trie<T> {
+    // Nodes are stored flat with the root first, children refer to them by index
+    nodes: Vec<Node<T>>,
+    words: usize,
+}
+
+impl<T: Eq + Hash + Clone> Default for Trie<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash + Clone> Trie<T> {
+    pub fn new() -> Self {
+        Trie {
+            nodes: vec![Node::new()],
+            words: 0,
+        }
+    }
+
+    /// Number of distinct words in the trie.
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    pub fn insert(&mut self, word: impl IntoIterator<Item = T>) {
+        let mut cur = 0;
+
+        for item in word {
+            cur = match self.nodes[cur].children.get(&item) {
+                Some(next) => *next,
+                None => {
+                    self.nodes.push(Node::new());
+                    let next = self.nodes.len() - 1;
+                    self.nodes[cur].children.insert(item, next);
+                    next
+                }
+            };
+        }
+
+        if !self.nodes[cur].is_word {
+            self.nodes[cur].is_word = true;
+            self.words += 1;
+        }
+    }
+
+    pub fn contains(&self, word: &[T]) -> bool {
+        let mut cur = 0;
+
+        for item in word {
+            match self.nodes[cur].children.get(item) {
+                Some(next) => cur = *next,
+                None => return false,
+            }
+        }
+
+        self.nodes[cur].is_word
+    }
+
+    /// End positions of all words that occur in `text` starting at `start`.
+    pub fn matches_at<'a>(
+        &'a self,
+        text: &'a [T],
+        start: usize,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let mut cur = Some(0);
+        let mut end = start;
+
+        std::iter::from_fn(move || {
+            while let Some(node) = cur {
+                let is_word = self.nodes[node].is_word;
+                let pos = end;
+
+                cur = text
+                    .get(end)
+                    .and_then(|item| self.nodes[node].children.get(item))
+                    .copied();
+                end += 1;
+
+                if is_word && pos > start {
+                    return Some(pos);
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Number of ways `text` can be split into words of the trie.
+    pub fn count_segmentations(&self, text: &[T]) -> usize {
+        self.segmentations_from(text)[0]
+    }
+
+    pub fn can_segment(&self, text: &[T]) -> bool {
+        self.count_segmentations(text) > 0
+    }
+
+    /// Every way `text` can be split into words of the trie.
+    pub fn enumerate_segmentations<'a>(&self, text: &'a [T]) -> Vec<Vec<&'a [T]>> {
+        let ways = self.segmentations_from(text);
+        let mut result = Vec::new();
+        let mut cur = Vec::new();
+
+        self.enumerate_from(text, 0, &ways, &mut cur, &mut result);
+
+        result
+    }
+
+    // Number of segmentations of every suffix, indexed by where it starts
+    fn segmentations_from(&self, text: &[T]) -> Vec<usize> {
+        let mut ways = vec![0; text.len() + 1];
+        ways[text.len()] = 1;
+
+        for start in (0..text.len()).rev() {
+            ways[start] = self.matches_at(text, start).map(|end| ways[end]).sum();
+        }
+
+        ways
+    }
+
+    fn enumerate_from<'a>(
+        &self,
+        text: &'a [T],
+        start: usize,
+        ways: &[usize],
+        cur: &mut Vec<&'a [T]>,
+        result: &mut Vec<Vec<&'a [T]>>,
+    ) {
+        if start == text.len() {
+            result.push(cur.clone());
+            return;
+        }
+
+        // Only words followed by a splittable rest lead anywhere
+        for end in self.matches_at(text, start) {
+            if ways[end] > 0 {
+                cur.push(&text[start..end]);
+                self.enumerate_from(text, end, ways, cur, result);
+                cur.pop();
+            }
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone, W: IntoIterator<Item = T>> FromIterator<W> for Trie<T> {
+    fn from_iter<I: IntoIterator<Item = W>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+
+        for word in iter {
+            trie.insert(word);
+        }
+
+        trie
+    }
+}
@@ -0,0 +1,173 @@
+use crate::grid::Grid;
+use std::borrow::Cow;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns on recording of animations, e.g. for a `--visualize` command line flag.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether solutions should record and save their animations.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub type Rgb = [u8; 3];
+
+/// Frames of a grid drawn with a fixed palette, where every cell becomes a square of
+/// `scale` by `scale` pixels.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    palette: Vec<Rgb>,
+    scale: usize,
+    delay_ms: u16,
+    width: usize,
+    height: usize,
+    // Palette indices of all pixels, row by row
+    frames: Vec<Vec<u8>>,
+}
+
+impl Animation {
+    pub fn new(palette: Vec<Rgb>, scale: usize, delay_ms: u16) -> Self {
+        assert!(
+            !palette.is_empty() && palette.len() <= 256,
+            "A palette has to have 1 to 256 colours"
+        );
+
+        Animation {
+            palette,
+            scale,
+            delay_ms,
+            width: 0,
+            height: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Adds the grid as the next frame, with `colour` giving the palette index of a cell.
+    /// All frames have to come from grids of the same size.
+    pub fn push_frame<T>(&mut self, grid: &Grid<T>, colour: impl Fn(&T) -> u8) {
+        let (width, height) = (grid.width() * self.scale, grid.height() * self.scale);
+
+        if self.frames.is_empty() {
+            (self.width, self.height) = (width, height);
+        }
+
+        assert!(
+            (width, height) == (self.width, self.height),
+            "Frames of an animation have to be equally large"
+        );
+
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for row in grid.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| {
+                    let index = colour(cell);
+                    assert!(
+                        (index as usize) < self.palette.len(),
+                        "Colour {} is not in the palette",
+                        index
+                    );
+
+                    std::iter::repeat_n(index, self.scale)
+                })
+                .collect();
+
+            for _ in 0..self.scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        self.frames.push(pixels);
+    }
+
+    /// Writes the frames as an endlessly repeating animation, an animated PNG if the path
+    /// ends in `.png` or `.apng` and a GIF otherwise. Missing directories are created.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+
+        if self.frames.is_empty() {
+            return Err(io::Error::other("An animation needs at least one frame"));
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let file = BufWriter::new(File::create(path)?);
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("png") | Some("apng") => self.write_apng(file),
+            _ => self.write_gif(file),
+        }
+    }
+
+    fn write_gif(&self, file: BufWriter<File>) -> io::Result<()> {
+        let (width, height) = self.dimensions()?;
+        let palette: Vec<u8> = self.palette.concat();
+
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for pixels in &self.frames {
+            let frame = gif::Frame {
+                width,
+                height,
+                // GIF delays are in hundredths of a second
+                delay: self.delay_ms / 10,
+                buffer: Cow::Borrowed(pixels),
+                ..gif::Frame::default()
+            };
+
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_apng(&self, file: BufWriter<File>) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(file, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(self.palette.concat());
+        encoder
+            .set_animated(self.frames.len() as u32, 0)
+            .map_err(io::Error::other)?;
+        encoder
+            .set_frame_delay(self.delay_ms, 1000)
+            .map_err(io::Error::other)?;
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+
+        for pixels in &self.frames {
+            writer.write_image_data(pixels).map_err(io::Error::other)?;
+        }
+
+        writer.finish().map_err(io::Error::other)
+    }
+
+    // GIF sizes are limited to 16 bits
+    fn dimensions(&self) -> io::Result<(u16, u16)> {
+        match (u16::try_from(self.width), u16::try_from(self.height)) {
+            (Ok(width), Ok(height)) => Ok((width, height)),
+            _ => Err(io::Error::other(format!(
+                "{}x{} pixels is too large for a GIF",
+                self.width, self.height
+            ))),
+        }
+    }
+}